@@ -0,0 +1,198 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use life::{BaseLifeBoard, BitBoard, Cell, DeadZoneOptimizedBoard, FlatLifeBoard, FrontierLifeBoard, HashLifeBoard, LifeBoard, ParallelLifeBoard, QuadTree};
+use life::patterns;
+
+const WIDTH: usize = 1920;
+const HEIGHT: usize = 1080;
+const STEPS: usize = 100;
+
+fn simulate_1920x1080_100_steps(c: &mut Criterion) {
+    let base_board = BaseLifeBoard::gen(WIDTH, HEIGHT, Cell::gen);
+    let bit_board = BitBoard::from_board(&base_board);
+    let flat_board = FlatLifeBoard::from_board(&base_board);
+    let mut group = c.benchmark_group("simulate_1920x1080_100_steps");
+    group.sample_size(10);
+    group.bench_function("BaseLifeBoard", |b| b.iter_batched(
+        || base_board.clone(),
+        |mut board| board.simulate_n_steps(STEPS),
+        BatchSize::LargeInput
+    ));
+    group.bench_function("BitBoard", |b| b.iter_batched(
+        || bit_board.clone(),
+        |mut board| board.simulate_n_steps(STEPS),
+        BatchSize::LargeInput
+    ));
+    group.bench_function("FlatLifeBoard", |b| b.iter_batched(
+        || flat_board.clone(),
+        |mut board| board.simulate_n_steps(STEPS),
+        BatchSize::LargeInput
+    ));
+    group.finish();
+}
+
+fn simulate_1000x1000(c: &mut Criterion) {
+    let base_board = BaseLifeBoard::gen(1000, 1000, Cell::gen);
+    let parallel_board = ParallelLifeBoard::from_board_auto(base_board.clone());
+    let mut group = c.benchmark_group("simulate_1000x1000");
+    group.bench_function("BaseLifeBoard", |b| b.iter_batched(
+        || base_board.clone(),
+        |mut board| board.simulate(),
+        BatchSize::LargeInput
+    ));
+    group.bench_function("ParallelLifeBoard", |b| b.iter_batched(
+        || parallel_board.clone(),
+        |mut board| board.simulate(),
+        BatchSize::LargeInput
+    ));
+    group.finish();
+}
+
+// Many generations of a small board, so the fixed cost of every parallel step outweighs the cells it steps.
+fn simulate_64x64_1000_steps(c: &mut Criterion) {
+    let base_board = BaseLifeBoard::gen_seeded(64, 64, 0.4, 4, Cell::from_bool);
+    let parallel_board = ParallelLifeBoard::from_board_auto(base_board.clone());
+    let mut group = c.benchmark_group("simulate_64x64_1000_steps");
+    group.sample_size(10);
+    group.bench_function("BaseLifeBoard", |b| b.iter_batched(
+        || base_board.clone(),
+        |mut board| board.simulate_n_steps(1000),
+        BatchSize::SmallInput
+    ));
+    group.bench_function("ParallelLifeBoard", |b| b.iter_batched(
+        || parallel_board.clone(),
+        |mut board| board.simulate_n_steps(1000),
+        BatchSize::SmallInput
+    ));
+    group.finish();
+}
+
+fn simulate_1920x1080_by_density(c: &mut Criterion) {
+    let mut group = c.benchmark_group("simulate_1920x1080_by_density");
+    group.sample_size(10);
+    for density in [0.05, 0.3, 0.6] {
+        let base_board = BaseLifeBoard::gen_seeded(WIDTH, HEIGHT, density, 1, Cell::from_bool);
+        let parallel_board = ParallelLifeBoard::from_board_auto(base_board.clone());
+        group.bench_function(format!("ParallelLifeBoard/{density}"), |b| b.iter_batched(
+            || parallel_board.clone(),
+            |mut board| board.simulate(),
+            BatchSize::LargeInput
+        ));
+        #[cfg(feature = "rayon")]
+        group.bench_function(format!("simulate_rayon/{density}"), |b| b.iter_batched(
+            || base_board.clone(),
+            |mut board| board.simulate_rayon(),
+            BatchSize::LargeInput
+        ));
+    }
+    group.finish();
+}
+
+fn simulate_square_boards(c: &mut Criterion) {
+    let mut group = c.benchmark_group("simulate_square_boards");
+    group.sample_size(10);
+    for size in [512, 2048] {
+        let base_board = BaseLifeBoard::gen_seeded(size, size, 0.3, 5, Cell::from_bool);
+        let parallel_board = ParallelLifeBoard::from_board_auto(base_board.clone());
+        group.bench_function(format!("BaseLifeBoard/{size}"), |b| b.iter_batched(
+            || base_board.clone(),
+            |mut board| board.simulate(),
+            BatchSize::LargeInput
+        ));
+        group.bench_function(format!("ParallelLifeBoard/{size}"), |b| b.iter_batched(
+            || parallel_board.clone(),
+            |mut board| board.simulate(),
+            BatchSize::LargeInput
+        ));
+        #[cfg(feature = "rayon")]
+        {
+            let rayon_board = life::RayonLifeBoard::from_board(base_board.clone());
+            group.bench_function(format!("RayonLifeBoard/{size}"), |b| b.iter_batched(
+                || rayon_board.clone(),
+                |mut board| board.simulate(),
+                BatchSize::LargeInput
+            ));
+        }
+    }
+    group.finish();
+}
+
+fn neighbor_count_10000x10000_1_percent(c: &mut Criterion) {
+    const SIZE: usize = 10000;
+    const WINDOW: usize = 100;
+    let board = BaseLifeBoard::gen_seeded(SIZE, SIZE, 0.01, 7, Cell::from_bool);
+    let mut tree = QuadTree::new(SIZE, SIZE);
+    for x in 0..SIZE {
+        for y in 0..SIZE {
+            if board.is_cell_alive(x, y) == Ok(true) {
+                tree.insert(x, y);
+            }
+        }
+    }
+    let mut group = c.benchmark_group("neighbor_count_10000x10000_1_percent");
+    group.sample_size(10);
+    group.bench_function("flat", |b| b.iter(||
+        (0..WINDOW).flat_map(|x| (0..WINDOW).map(move |y| (x, y)))
+            .map(|(x, y)| board.num_alive_neighbors_at(x, y).unwrap() as usize)
+            .sum::<usize>()
+    ));
+    group.bench_function("QuadTree", |b| b.iter(||
+        (0..WINDOW).flat_map(|x| (0..WINDOW).map(move |y| (x, y)))
+            .map(|(x, y)| tree.count_in(x.saturating_sub(1)..x + 2, y.saturating_sub(1)..y + 2) - tree.contains(x, y) as usize)
+            .sum::<usize>()
+    ));
+    group.finish();
+}
+
+fn simulate_sparse_glider_100_steps(c: &mut Criterion) {
+    let mut base_board = BaseLifeBoard::from_rle("x = 3, y = 3\nbo$2bo$3o!", Cell::from_bool).unwrap();
+    base_board.resize(WIDTH, HEIGHT, false).unwrap();
+    let dead_zone_board = DeadZoneOptimizedBoard::from_board(base_board.clone());
+    let mut group = c.benchmark_group("simulate_sparse_glider_100_steps");
+    group.sample_size(10);
+    group.bench_function("BaseLifeBoard", |b| b.iter_batched(
+        || base_board.clone(),
+        |mut board| board.simulate_n_steps(STEPS),
+        BatchSize::LargeInput
+    ));
+    group.bench_function("DeadZoneOptimizedBoard", |b| b.iter_batched(
+        || dead_zone_board.clone(),
+        |mut board| board.simulate_n_steps(STEPS),
+        BatchSize::LargeInput
+    ));
+    group.finish();
+}
+
+fn simulate_2000x2000_glider_gun_100_steps(c: &mut Criterion) {
+    let mut base_board = patterns::gosper_glider_gun(Cell::from_bool);
+    base_board.resize(2000, 2000, false).unwrap();
+    base_board.translate(20, 20, false);
+    let frontier_board = FrontierLifeBoard::from_board(base_board.clone());
+    let mut group = c.benchmark_group("simulate_2000x2000_glider_gun_100_steps");
+    group.sample_size(10);
+    group.bench_function("BaseLifeBoard", |b| b.iter_batched(
+        || base_board.clone(),
+        |mut board| board.simulate_n_steps(STEPS),
+        BatchSize::LargeInput
+    ));
+    group.bench_function("FrontierLifeBoard", |b| b.iter_batched(
+        || frontier_board.clone(),
+        |mut board| board.simulate_n_steps(STEPS),
+        BatchSize::LargeInput
+    ));
+    group.finish();
+}
+
+fn hashlife_glider_gun_2_pow_20_steps(c: &mut Criterion) {
+    let board = HashLifeBoard::from_board(&patterns::gosper_glider_gun(Cell::from_bool));
+    let mut group = c.benchmark_group("hashlife_glider_gun_2_pow_20_steps");
+    group.sample_size(10);
+    group.bench_function("HashLifeBoard", |b| b.iter_batched(
+        || board.clone(),
+        |mut board| board.simulate_power_of_two_steps(20).unwrap(),
+        BatchSize::LargeInput
+    ));
+    group.finish();
+}
+
+criterion_group!(benches, simulate_1920x1080_100_steps, simulate_1000x1000, simulate_64x64_1000_steps, simulate_1920x1080_by_density, simulate_square_boards, neighbor_count_10000x10000_1_percent, simulate_sparse_glider_100_steps, simulate_2000x2000_glider_gun_100_steps, hashlife_glider_gun_2_pow_20_steps);
+criterion_main!(benches);
@@ -0,0 +1,26 @@
+// With the `ffi` feature, generates the C header for the `extern "C"` functions in `src/lib/life_ffi.rs` into `OUT_DIR`
+// and warns when the checked-in `include/life.h` no longer matches it. The source tree itself is never written to.
+fn main() {
+    #[cfg(feature = "ffi")]
+    {
+        let crate_dir = std::path::PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").expect("Cargo should always set CARGO_MANIFEST_DIR"));
+        let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR").expect("Cargo should always set OUT_DIR"));
+        println!("cargo:rerun-if-changed=src/lib/life_ffi.rs");
+        println!("cargo:rerun-if-changed=cbindgen.toml");
+        println!("cargo:rerun-if-changed=include/life.h");
+        let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml")).expect("Unable to read cbindgen.toml");
+        let header = out_dir.join("life.h");
+        cbindgen::Builder::new()
+            .with_config(config)
+            .with_src(crate_dir.join("src/lib/life_ffi.rs"))
+            .generate()
+            .expect("Unable to generate C bindings")
+            .write_to_file(&header);
+        if std::fs::read(&header).ok() != std::fs::read(crate_dir.join("include/life.h")).ok() {
+            println!(
+                "cargo:warning=include/life.h is out of date, regenerate it with `cbindgen --config cbindgen.toml --output include/life.h src/lib/life_ffi.rs`"
+            );
+        }
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,74 @@
+#ifndef LIFE_H
+#define LIFE_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define LIFE_OK 0
+
+#define LIFE_ERROR_NULL_POINTER -1
+
+#define LIFE_ERROR_OUT_OF_BOUNDS -2
+
+#define LIFE_ERROR_INVALID_ARGUMENT -3
+
+#define LIFE_ERROR_PANIC -4
+
+typedef struct LifeHandle LifeHandle;
+
+/**
+ * Creates a random board where each cell is alive with probability `density`. Returns null if either dimension is zero.
+ */
+struct LifeHandle *life_board_new(uintptr_t width,
+                                  uintptr_t height,
+                                  double density,
+                                  uint64_t seed);
+
+/**
+ * Creates a board from `len` row-major bytes where any non-zero byte is alive. Returns null if `len` is not `width * height`.
+ *
+ * # Safety
+ * `cells` must be null or point to at least `len` readable bytes.
+ */
+struct LifeHandle *life_board_from_cells(const uint8_t *cells,
+                                         uintptr_t len,
+                                         uintptr_t width,
+                                         uintptr_t height);
+
+/**
+ * # Safety
+ * `handle` must be null or a live pointer returned by this library.
+ */
+int32_t life_board_step(struct LifeHandle *handle);
+
+/**
+ * # Safety
+ * `handle` must be null or a live pointer returned by this library.
+ */
+int32_t life_board_step_n(struct LifeHandle *handle, uintptr_t n);
+
+/**
+ * Returns 1 if the cell is alive, 0 if it is dead, or a negative error code.
+ *
+ * # Safety
+ * `handle` must be null or a live pointer returned by this library.
+ */
+int32_t life_board_get_cell(const struct LifeHandle *handle, uintptr_t x, uintptr_t y);
+
+/**
+ * Writes one row-major byte per cell (1 alive, 0 dead). `len` must be exactly `width * height`.
+ *
+ * # Safety
+ * `handle` must be null or a live pointer returned by this library, and `buffer` must be null or point to `len` writable bytes.
+ */
+int32_t life_board_fill_buffer(const struct LifeHandle *handle,
+                               uint8_t *buffer,
+                               uintptr_t len);
+
+/**
+ * # Safety
+ * `handle` must be null or a pointer returned by this library that has not already been freed.
+ */
+void life_board_free(struct LifeHandle *handle);
+
+#endif /* LIFE_H */
@@ -0,0 +1,134 @@
+use std::io;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use life::{BaseLifeBoard, Cell, LifeBoard, LifeBoardError, ParallelLifeBoard, Replay};
+
+const EXIT_IO: u8 = 1;  // Reading stdin or writing stdout failed
+const EXIT_USAGE: u8 = 2;  // Unknown flags or invalid flag values
+const EXIT_PARSE: u8 = 3;  // The board on stdin could not be parsed
+const USAGE: &str = "Usage: game_of_life_cli [--format rle|plaintext] [--steps N] [--threads N] [--record PATH] < board > board";
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum Format { Rle, Plaintext }
+
+#[derive(PartialEq, Clone, Debug)]
+struct Options {
+    format: Format,
+    steps: usize,
+    /// `None` picks the thread count from the available parallelism.
+    n_threads: Option<u8>,
+    /// Where to save a `Replay` of every generation, if anywhere.
+    record: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{error}\n{USAGE}");
+            return ExitCode::from(EXIT_USAGE);
+        },
+    };
+    let mut input = String::new();
+    if let Err(error) = io::stdin().read_to_string(&mut input) {
+        eprintln!("Unable to read stdin: {error}");
+        return ExitCode::from(EXIT_IO);
+    }
+    let board = match read_board(&input, options.format) {
+        Ok(board) => board,
+        Err(error) => {
+            eprintln!("Unable to parse board: {error:?}");
+            return ExitCode::from(EXIT_PARSE);
+        },
+    };
+    let mut game = match options.n_threads {
+        Some(n_threads) => ParallelLifeBoard::from_board(board, n_threads),
+        None => ParallelLifeBoard::from_board_auto(board),
+    };
+    match &options.record {
+        Some(path) => if let Err(error) = Replay::record(&mut game, options.steps).save(path) {
+            eprintln!("Unable to save replay: {error:?}");
+            return ExitCode::from(EXIT_IO);
+        },
+        None => game.simulate_n_steps(options.steps),
+    }
+    let mut stdout = io::stdout().lock();
+    if let Err(error) = stdout.write_all(write_board(&game.into_board(), options.format).as_bytes()).and_then(|_| stdout.flush()) {
+        eprintln!("Unable to write stdout: {error}");
+        return ExitCode::from(EXIT_IO);
+    }
+    ExitCode::SUCCESS
+}
+
+fn parse_args<I: Iterator<Item=String>>(mut args: I) -> Result<Options, String> {
+    let mut options = Options { format: Format::Rle, steps: 1, n_threads: None, record: None };
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| format!("Missing value for \"{flag}\"."))?;
+        match flag.as_str() {
+            "--format" => options.format = match value.as_str() {
+                "rle" => Format::Rle,
+                "plaintext" => Format::Plaintext,
+                _ => return Err(format!("Unknown format \"{value}\", expected rle or plaintext.")),
+            },
+            "--steps" => options.steps = value.parse().map_err(|_| format!("Expected a number of steps but found \"{value}\"."))?,
+            "--threads" => options.n_threads = match value.parse() {
+                Ok(0) | Err(_) => return Err(format!("Expected between 1 and 255 threads but found \"{value}\".")),
+                Ok(n_threads) => Some(n_threads),
+            },
+            "--record" => options.record = Some(PathBuf::from(value)),
+            _ => return Err(format!("Unknown flag \"{flag}\".")),
+        }
+    }
+    Ok(options)
+}
+
+fn read_board(input: &str, format: Format) -> Result<BaseLifeBoard<Cell>, LifeBoardError> {
+    match format {
+        Format::Rle => BaseLifeBoard::from_rle(input, Cell::from_bool),
+        Format::Plaintext => BaseLifeBoard::from_plaintext(input, Cell::from_bool),
+    }
+}
+
+fn write_board(board: &BaseLifeBoard<Cell>, format: Format) -> String {
+    match format {
+        Format::Rle => board.to_rle(),
+        Format::Plaintext => board.to_plaintext(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use life::{BaseLifeBoard, Cell, LifeBoard};
+    use crate::{parse_args, read_board, write_board, Format, Options};
+
+    fn args(args: &[&str]) -> impl Iterator<Item=String> {
+        args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>().into_iter()
+    }
+
+    #[test]
+    fn test_equivalence_parse_args() {
+        assert_eq!(Ok(Options { format: Format::Rle, steps: 1, n_threads: None, record: None }), parse_args(args(&[])));
+        assert_eq!(
+            Ok(Options { format: Format::Plaintext, steps: 50, n_threads: Some(3), record: Some(PathBuf::from("run.replay")) }),
+            parse_args(args(&["--steps", "50", "--format", "plaintext", "--threads", "3", "--record", "run.replay"]))
+        );
+    }
+
+    #[test]
+    fn test_exception_parse_args_invalid() {
+        for invalid in [&["--steps"][..], &["--steps", "-1"], &["--threads", "0"], &["--format", "mc"], &["--speed", "3"]] {
+            assert!(parse_args(args(invalid)).is_err(), "{invalid:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_equivalence_plaintext_ragged_rows_and_comments() {
+        let board = read_board("!Name: Glider\n.O\n..O\nOOO\n", Format::Plaintext).unwrap();
+        assert_eq!((3, 3, 5), (board.width(), board.height(), board.count_alive()));
+        assert_eq!(".O.\n..O\nOOO\n", write_board(&board, Format::Plaintext));
+        let rle_board = BaseLifeBoard::from_rle("x = 3, y = 3\nbo$2bo$3o!", Cell::from_bool).unwrap();
+        assert_eq!(rle_board, board);
+    }
+}
@@ -1,42 +1,165 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
 use std::time::{Duration, Instant};
 use pixels::{Pixels, SurfaceTexture};
-use rand::Rng;
+use serde::Deserialize;
 use winit::dpi::LogicalSize;
 use winit::event::{Event, VirtualKeyCode};
 use winit::event_loop::{ControlFlow, EventLoop};
 use winit::window::{Window, WindowBuilder};
 use winit_input_helper::WinitInputHelper;
-use life::{BaseLifeBoard, ParallelLifeBoard, LifeBoard, LifeCell};
+use life::{BaseLifeBoard, BoolOp, Cell, ParallelLifeBoard, LifeBoard, LifeBoardError, LifeCell, Session};
+use life::patterns::{self, NamedPattern};
 
-const SCALE: u32 = 4;  // How many logical pixels correspond to one `LifeCell`
-const WIDTH: u32 = 1920;  // Width of the window
-const HEIGHT: u32 = 1080;  // Height of the window
-const N_THREADS: u8 = 5;  // Number of threads to use in the ParallelLifeBoard
-const MS_TIME_STEP: u64 = 250;  // Separation between frame redraws when in auto mode
-const DEAD_COLOR: Color = Color(0, 0, 0, 0xff);  // Cell dead color
-const ALIVE_COLOR: Color = Color(0x30, 0xff, 0xff, 0xff);  // Cell alive color
+const CONFIG_FILE_NAME: &str = "life.toml";  // Name of the config file searched for on startup
+const SESSION_FILE_NAME: &str = "life_session.json";  // Session saved with S and restored with L
+const STEP_RATE_WINDOW: usize = 60;  // Number of recent steps averaged into the displayed step rate
+const MIN_STEP_MS: u64 = 1;  // Fastest auto-step interval reachable with +
+const MAX_STEP_MS: u64 = 10_000;  // Slowest auto-step interval reachable with -
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Deserialize)]
 struct Color(u8, u8, u8, u8);
 impl Color {
     fn to_array(&self) -> [u8; 4] { [self.0, self.1, self.2, self.3] }
 }
-#[derive(PartialEq, Clone, Debug)]
-struct ColorCell { alive: bool, color: Color } impl ColorCell {
-    pub fn gen() -> ColorCell {
-        let alive = rand::thread_rng().gen_bool(0.5);
-        let color = if alive { ALIVE_COLOR } else { DEAD_COLOR };
-        ColorCell { alive, color }
+
+/// Startup configuration read from `life.toml`. Every field is optional and falls back to its default.
+#[derive(PartialEq, Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    /// How many logical pixels correspond to one cell.
+    scale: u32,
+    /// Width of the window in logical pixels.
+    width: u32,
+    /// Height of the window in logical pixels.
+    height: u32,
+    /// Number of threads to use in the `ParallelLifeBoard`.
+    n_threads: u8,
+    /// Separation in milliseconds between frame redraws when in auto mode.
+    ms_time_step: u64,
+    /// Dead cell color as `[r, g, b, a]`.
+    dead_color: Color,
+    /// Alive cell color as `[r, g, b, a]`.
+    alive_color: Color,
+    /// Either `"random"` or the path to an RLE file that is centered on the board.
+    initial_pattern: String,
+    /// Behavior at the board edges: `"dead"` or `"toroidal"`, which wraps both axes around.
+    boundary: String,
+    /// Directory of pattern files to cycle through with `[` and `]`, if any.
+    pattern_dir: Option<PathBuf>,
+} impl Config {
+    fn from_file(path: &Path) -> Result<Config, Box<dyn Error>> {
+        Config::from_toml(&fs::read_to_string(path)?)
     }
-} impl LifeCell<ColorCell> for ColorCell {
-    fn is_alive(&self) -> bool { self.alive }
 
-    fn to_alive(&self) -> ColorCell {
-        ColorCell { alive: true, color: ALIVE_COLOR }
+    fn from_toml(text: &str) -> Result<Config, Box<dyn Error>> {
+        let config: Config = toml::from_str(text)?;
+        if config.scale == 0 || config.width < config.scale || config.height < config.scale {
+            return Err("The window must be at least one cell wide and tall.".into());
+        }
+        if config.n_threads == 0 {
+            return Err("At least one thread is required.".into());
+        }
+        if !matches!(config.boundary.as_str(), "dead" | "toroidal") {
+            return Err(format!("Unsupported boundary \"{}\".", config.boundary).into());
+        }
+        Ok(config)
     }
 
-    fn to_dead(&self) -> ColorCell {
-        ColorCell { alive: false, color: DEAD_COLOR }
+    fn load_default() -> Config {
+        let mut paths = vec![PathBuf::from(CONFIG_FILE_NAME)];
+        if let Some(home) = std::env::var_os("HOME") {
+            paths.push(Path::new(&home).join(".config").join("lifegame").join(CONFIG_FILE_NAME));
+        }
+        for path in paths.iter().filter(|path| path.is_file()) {
+            match Config::from_file(path) {
+                Ok(config) => return config,
+                Err(error) => eprintln!("Ignoring {}: {error}", path.display()),
+            }
+        }
+        Config::default()
+    }
+
+    fn board_width(&self) -> u32 { self.width / self.scale }
+
+    fn board_height(&self) -> u32 { self.height / self.scale }
+
+    fn is_toroidal(&self) -> bool { self.boundary == "toroidal" }
+} impl Default for Config {
+    fn default() -> Self {
+        Config {
+            scale: 4,
+            width: 1920,
+            height: 1080,
+            n_threads: thread::available_parallelism().map_or(1, |n| n.get().min(u8::MAX as usize) as u8),
+            ms_time_step: 250,
+            dead_color: Color(0, 0, 0, 0xff),
+            alive_color: Color(0x30, 0xff, 0xff, 0xff),
+            initial_pattern: String::from("random"),
+            boundary: String::from("dead"),
+            pattern_dir: None,
+        }
+    }
+}
+
+/// Simulation speed and progress shown in the window title.
+struct SimStats {
+    /// Steps per second averaged over the last `STEP_RATE_WINDOW` steps.
+    actual_step_rate: f64,
+    /// Frames per second based on the time between the last two redraws.
+    render_fps: f64,
+    generation: u64,
+    population: usize,
+    step_intervals: [Duration; STEP_RATE_WINDOW],
+    next_interval: usize,
+    n_intervals: usize,
+    last_step: Option<Instant>,
+    last_frame: Option<Instant>,
+} impl SimStats {
+    fn new(generation: u64, population: usize) -> SimStats {
+        SimStats {
+            actual_step_rate: 0.0,
+            render_fps: 0.0,
+            generation,
+            population,
+            step_intervals: [Duration::ZERO; STEP_RATE_WINDOW],
+            next_interval: 0,
+            n_intervals: 0,
+            last_step: None,
+            last_frame: None,
+        }
+    }
+
+    fn record_step(&mut self, now: Instant, population: usize) {
+        self.generation += 1;
+        self.population = population;
+        if let Some(last_step) = self.last_step {
+            self.step_intervals[self.next_interval] = now - last_step;
+            self.next_interval = (self.next_interval + 1) % STEP_RATE_WINDOW;
+            self.n_intervals = (self.n_intervals + 1).min(STEP_RATE_WINDOW);
+            let total: Duration = self.step_intervals[..self.n_intervals].iter().sum();
+            self.actual_step_rate = if total.is_zero() { 0.0 } else { self.n_intervals as f64 / total.as_secs_f64() };
+        }
+        self.last_step = Some(now);
+    }
+
+    fn record_frame(&mut self, now: Instant) {
+        if let Some(elapsed) = self.last_frame.map(|last_frame| now - last_frame).filter(|elapsed| !elapsed.is_zero()) {
+            self.render_fps = 1.0 / elapsed.as_secs_f64();
+        }
+        self.last_frame = Some(now);
+    }
+
+    // Jumping to another generation (e.g. loading a session) restarts the rolling average.
+    fn reset(&mut self, generation: u64, population: usize) {
+        *self = SimStats { render_fps: self.render_fps, last_frame: self.last_frame, ..SimStats::new(generation, population) };
+    }
+
+    fn title(&self) -> String {
+        format!("Life | Gen {} | Pop {} | {:.1} steps/s | {:.1} fps",
+                self.generation, self.population, self.actual_step_rate, self.render_fps)
     }
 }
 
@@ -44,20 +167,30 @@ fn main() {
     let event_loop = EventLoop::new();
     let mut input = WinitInputHelper::new();
     let mut auto_step: bool = false;
-    let window = initialize_window(&event_loop);
-    let mut pixels = initialize_pixel_buffer(&window);
-    let mut game = initialize_life_board();
+    let config = Config::load_default();
+    let window = initialize_window(&event_loop, &config);
+    let mut pixels = initialize_pixel_buffer(&window, &config);
+    let mut game = initialize_life_board(&config).unwrap_or_else(|error|
+        panic!("Unable to load initial pattern {}: {error:?}", config.initial_pattern)
+    );
+    let mut stats = SimStats::new(0, game.count_alive());
+    let mut target_step_ms = config.ms_time_step;
     let mut last_frame_time = Instant::now();
+    let pattern_library = load_pattern_library(&config);
+    let mut pattern_idx: Option<usize> = None;
 
     event_loop.run(move |event, _, control_flow| {
         if let Event::RedrawRequested(_) = event {
-            redraw_world(&mut pixels, &mut game);
+            redraw_world(&mut pixels, &mut game, &config);
+            stats.record_frame(Instant::now());
+            window.set_title(&stats.title());
         } else if let Event::MainEventsCleared = event {
             let now = Instant::now();
             let elapsed = now - last_frame_time;
-            if elapsed >= Duration::from_millis(MS_TIME_STEP) && auto_step {
+            if elapsed >= Duration::from_millis(target_step_ms) && auto_step {
                 last_frame_time = now;
                 game.simulate();
+                stats.record_step(now, game.count_alive());
                 window.request_redraw();
             }
         }
@@ -65,54 +198,292 @@ fn main() {
         if input.update(&event) {
             if input.key_pressed(VirtualKeyCode::Escape) || input.close_requested() {
                 *control_flow = ControlFlow::ExitWithCode(0);
-                return;
+            } else if input.held_control() && input.key_pressed(VirtualKeyCode::C) {
+                if let Err(error) = copy_board_to_clipboard(&game) {
+                    eprintln!("Unable to copy board: {error}");
+                }
+            } else if input.held_control() && input.key_pressed(VirtualKeyCode::V) {
+                let (x, y) = input.mouse()
+                    .and_then(|position| pixels.window_pos_to_pixel(position).ok())
+                    .unwrap_or((0, 0));
+                match paste_pattern_from_clipboard(&game, x, y) {
+                    Ok(pasted_game) => {
+                        game = pasted_game;
+                        window.request_redraw();
+                    },
+                    Err(error) => eprintln!("Unable to paste pattern: {error}"),
+                }
             } else if input.key_pressed(VirtualKeyCode::Space) {
                 game.simulate();
+                stats.record_step(Instant::now(), game.count_alive());
                 window.request_redraw();
-                return;
             } else if input.key_pressed(VirtualKeyCode::P) {
-                auto_step = if auto_step { false } else { true };
-                return;
+                auto_step = !auto_step;
+            } else if input.key_pressed(VirtualKeyCode::Plus) || input.key_pressed(VirtualKeyCode::Equals)
+                || input.key_pressed(VirtualKeyCode::NumpadAdd) {
+                target_step_ms = faster_step_ms(target_step_ms);
+            } else if input.key_pressed(VirtualKeyCode::Minus) || input.key_pressed(VirtualKeyCode::NumpadSubtract) {
+                target_step_ms = slower_step_ms(target_step_ms);
+            } else if (input.key_pressed(VirtualKeyCode::LBracket) || input.key_pressed(VirtualKeyCode::RBracket))
+                && !pattern_library.is_empty() {
+                let idx = cycle_pattern_idx(pattern_idx, pattern_library.len(), input.key_pressed(VirtualKeyCode::RBracket));
+                let pattern = &pattern_library[idx];
+                match center_pattern(pattern.board.clone(), game.width(), game.height()) {
+                    Ok(board) => {
+                        game = new_game(board, &config);
+                        pattern_idx = Some(idx);
+                        stats.reset(0, game.count_alive());
+                        window.request_redraw();
+                    },
+                    Err(error) => eprintln!("Unable to load pattern {}: {error}", pattern.name),
+                }
+            } else if input.key_pressed(VirtualKeyCode::S) {
+                let session = Session { generation: stats.generation, ..Session::from(game.clone()) };
+                if let Err(error) = session.save(SESSION_FILE_NAME) {
+                    eprintln!("Unable to save session: {error:?}");
+                }
+            } else if input.key_pressed(VirtualKeyCode::L) {
+                match load_session(Path::new(SESSION_FILE_NAME), &config) {
+                    Ok((loaded_game, loaded_generation)) => {
+                        game = loaded_game;
+                        stats.reset(loaded_generation, game.count_alive());
+                        window.request_redraw();
+                    },
+                    Err(error) => eprintln!("Unable to load session: {error:?}"),
+                }
             }
         }
     });
 }
 
-fn redraw_world(pixels: &mut Pixels, game: &mut ParallelLifeBoard<ColorCell>) {
+// Halves the auto-step interval, so each press of + roughly doubles the step rate.
+fn faster_step_ms(step_ms: u64) -> u64 { (step_ms / 2).max(MIN_STEP_MS) }
+
+fn slower_step_ms(step_ms: u64) -> u64 { step_ms.saturating_mul(2).clamp(MIN_STEP_MS, MAX_STEP_MS) }
+
+fn redraw_world(pixels: &mut Pixels, game: &mut ParallelLifeBoard<Cell>, config: &Config) {
     let frame = pixels.frame_mut();
     for (i, pixel) in frame.chunks_exact_mut(4).enumerate() {
-        let x = i % (WIDTH / SCALE) as usize;
-        let y = i / (WIDTH / SCALE) as usize;
-        if let Some(cell) = game.cell_at(x, y) {
-            pixel.copy_from_slice(&cell.color.to_array())
+        let x = i % config.board_width() as usize;
+        let y = i / config.board_width() as usize;
+        if let Ok(cell) = game.cell_at(x, y) {
+            let color = if cell.is_alive() { &config.alive_color } else { &config.dead_color };
+            pixel.copy_from_slice(&color.to_array())
         }
     }
     pixels.render().expect("Unable to render pixel buffer.");
 }
 
-fn initialize_window(event_loop: &EventLoop<()>) -> Window {
-    let size = LogicalSize::new(WIDTH, HEIGHT);
+fn initialize_window(event_loop: &EventLoop<()>, config: &Config) -> Window {
+    let size = LogicalSize::new(config.width, config.height);
     WindowBuilder::new()
         .with_title("Conway's Game of Life")
         .with_min_inner_size(size)
         .with_inner_size(size)
-        .build(&event_loop)
+        .build(event_loop)
         .unwrap()
 }
 
-fn initialize_life_board() -> ParallelLifeBoard<ColorCell> {
-    ParallelLifeBoard::<ColorCell>::from_board(
-        BaseLifeBoard::gen(
-            (WIDTH / SCALE) as usize,
-            (HEIGHT / SCALE) as usize,
-            ColorCell::gen
-        ),
-        N_THREADS
-    )
+fn initialize_life_board(config: &Config) -> Result<ParallelLifeBoard<Cell>, Box<dyn Error>> {
+    let (width, height) = (config.board_width() as usize, config.board_height() as usize);
+    let board = match config.initial_pattern.as_str() {
+        "random" => BaseLifeBoard::gen(width, height, Cell::gen),
+        path => load_centered_pattern(Path::new(path), width, height)?,
+    };
+    Ok(new_game(board, config))
+}
+
+fn new_game(mut board: BaseLifeBoard<Cell>, config: &Config) -> ParallelLifeBoard<Cell> {
+    board.set_wrap(config.is_toroidal(), config.is_toroidal());
+    ParallelLifeBoard::from_board(board, config.n_threads)
+}
+
+fn load_centered_pattern(path: &Path, width: usize, height: usize) -> Result<BaseLifeBoard<Cell>, Box<dyn Error>> {
+    let board = BaseLifeBoard::from_rle(&fs::read_to_string(path)?, Cell::from_bool)?;
+    center_pattern(board, width, height)
+}
+
+fn center_pattern(mut board: BaseLifeBoard<Cell>, width: usize, height: usize) -> Result<BaseLifeBoard<Cell>, Box<dyn Error>> {
+    let (dx, dy) = ((width as i64 - board.width() as i64) / 2, (height as i64 - board.height() as i64) / 2);
+    // Centering a pattern larger than the board clips it evenly on both sides.
+    board.translate(dx.min(0), dy.min(0), false);
+    board.resize(width, height, false).map_err(|error| format!("{error:?}"))?;
+    board.translate(dx.max(0), dy.max(0), false);
+    Ok(board)
+}
+
+fn load_pattern_library(config: &Config) -> Vec<NamedPattern<Cell>> {
+    let Some(pattern_dir) = &config.pattern_dir else { return Vec::new() };
+    match patterns::load_dir(pattern_dir, Cell::from_bool) {
+        Ok(loaded) => {
+            for warning in &loaded.warnings {
+                eprintln!("Skipping pattern: {warning:?}");
+            }
+            loaded.patterns
+        },
+        Err(error) => {
+            eprintln!("Unable to load patterns: {error:?}");
+            Vec::new()
+        },
+    }
+}
+
+// Moves to the next (or previous) pattern, wrapping around and starting from either end when none is loaded yet.
+fn cycle_pattern_idx(current: Option<usize>, n_patterns: usize, forward: bool) -> usize {
+    match (current, forward) {
+        (None, true) => 0,
+        (None, false) => n_patterns - 1,
+        (Some(idx), true) => (idx + 1) % n_patterns,
+        (Some(idx), false) => (idx + n_patterns - 1) % n_patterns,
+    }
+}
+
+fn copy_board_to_clipboard(game: &ParallelLifeBoard<Cell>) -> Result<(), Box<dyn Error>> {
+    arboard::Clipboard::new()?.set_text(game.to_rle())?;
+    Ok(())
+}
+
+fn paste_pattern_from_clipboard(game: &ParallelLifeBoard<Cell>, x: usize, y: usize) -> Result<ParallelLifeBoard<Cell>, Box<dyn Error>> {
+    let rle = arboard::Clipboard::new()?.get_text()?;
+    stamp_pattern(game, &rle, x, y).map_err(|error| format!("{error:?}").into())
+}
+
+// Ors the RLE pattern onto the board with its top-left corner at (x, y), clipping anything off the board.
+fn stamp_pattern(game: &ParallelLifeBoard<Cell>, rle: &str, x: usize, y: usize) -> Result<ParallelLifeBoard<Cell>, LifeBoardError> {
+    let mut pattern = BaseLifeBoard::from_rle(rle, Cell::from_bool)?;
+    pattern.resize(game.width(), game.height(), false)?;
+    pattern.translate(x as i64, y as i64, false);
+    let board = game.clone().into_board().combine(&pattern, BoolOp::Or)?;
+    Ok(ParallelLifeBoard::from_board(board, game.n_threads()))
 }
 
-fn initialize_pixel_buffer(window: &Window) -> Pixels {
+// Sessions saved from a differently sized window are cropped or padded to fit the current one.
+fn load_session(path: &Path, config: &Config) -> Result<(ParallelLifeBoard<Cell>, u64), LifeBoardError> {
+    let session = Session::load(path)?;
+    let generation = session.generation;
+    let mut game = ParallelLifeBoard::from(session);
+    game.resize(config.board_width() as usize, config.board_height() as usize, false)?;
+    Ok((game, generation))
+}
+
+fn initialize_pixel_buffer(window: &Window, config: &Config) -> Pixels {
     let window_size = window.inner_size();
     let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
-    Pixels::new(WIDTH / SCALE, HEIGHT / SCALE, surface_texture).expect("Unable to create pixel buffer")
-}
\ No newline at end of file
+    Pixels::new(config.board_width(), config.board_height(), surface_texture).expect("Unable to create pixel buffer")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use life::LifeBoard;
+    use life::{BaseLifeBoard, Cell, Session};
+    use life::ParallelLifeBoard;
+    use std::time::{Duration, Instant};
+    use crate::{cycle_pattern_idx, faster_step_ms, initialize_life_board, load_centered_pattern, load_session, slower_step_ms, stamp_pattern, Color, Config, SimStats, STEP_RATE_WINDOW};
+
+    #[test]
+    fn test_equivalence_config_from_toml_partial_overrides_defaults() {
+        let config = Config::from_toml("scale = 2\nn_threads = 3\nalive_color = [255, 0, 0, 255]\n").unwrap();
+        let expected_config = Config { scale: 2, n_threads: 3, alive_color: Color(255, 0, 0, 255), ..Config::default() };
+        assert_eq!(expected_config, config);
+        assert_eq!((960, 540), (config.board_width(), config.board_height()));
+    }
+
+    #[test]
+    fn test_equivalence_config_from_toml_empty_is_default() {
+        assert_eq!(Config::default(), Config::from_toml("").unwrap());
+    }
+
+    #[test]
+    fn test_equivalence_config_toroidal_boundary_wraps_board() {
+        let config = Config::from_toml("boundary = \"toroidal\"\nwidth = 40\nheight = 20\n").unwrap();
+        let game = initialize_life_board(&config).unwrap();
+        assert!(game.wrap_x() && game.wrap_y(), "Toroidal boards should wrap both axes");
+        let game = initialize_life_board(&Config { width: 40, height: 20, ..Config::default() }).unwrap();
+        assert!(!game.wrap_x() && !game.wrap_y(), "Dead boundaries should not wrap");
+    }
+
+    #[test]
+    fn test_exception_config_from_toml_invalid_values() {
+        assert!(Config::from_toml("n_threads = 0").is_err(), "Zero threads should be rejected");
+        assert!(Config::from_toml("scale = 0").is_err(), "Zero scale should be rejected");
+        assert!(Config::from_toml("boundary = \"klein\"").is_err(), "Unknown boundaries should be rejected");
+        assert!(Config::from_toml("speed = 3").is_err(), "Unknown keys should be rejected");
+        assert!(Config::from_toml("width = \"wide\"").is_err(), "Mistyped values should be rejected");
+    }
+
+    #[test]
+    fn test_equivalence_stamp_pattern_round_trips_rle() {
+        let game = ParallelLifeBoard::from_board(BaseLifeBoard::from_rle("x = 6, y = 4\no!", Cell::from_bool).unwrap(), 2);
+        let stamped = stamp_pattern(&game, "x = 3, y = 3\nbo$2bo$3o!", 4, 2).unwrap();
+        assert_eq!("x = 6, y = 4, rule = B3/S23\no2$5bo!\n", stamped.to_rle());
+        assert_eq!(2, stamped.n_threads());
+        let copied = BaseLifeBoard::from_rle(&stamped.to_rle(), Cell::from_bool).unwrap();
+        assert_eq!(stamped, ParallelLifeBoard::from_board(copied, 2));
+        assert!(stamp_pattern(&game, "not rle", 0, 0).is_err(), "Invalid RLE should be rejected");
+    }
+
+    #[test]
+    fn test_equivalence_load_session_fits_window() {
+        let path = std::env::temp_dir().join(format!("life_gui_session_{}.json", std::process::id()));
+        let session = Session { generation: 7, n_threads: 2, ..Session::from(BaseLifeBoard::gen(3, 30, Cell::gen)) };
+        session.save(&path).unwrap();
+        let config = Config { width: 40, height: 20, ..Config::default() };
+        let (game, generation) = load_session(&path, &config).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!((10, 5, 7, 2), (game.width(), game.height(), generation, game.n_threads()));
+    }
+
+    #[test]
+    fn test_equivalence_config_from_file_and_centered_pattern() {
+        let dir = std::env::temp_dir().join(format!("life_config_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let pattern_path = dir.join("glider.rle");
+        fs::write(&pattern_path, "x = 3, y = 3\nbo$2bo$3o!").unwrap();
+        let config_path = dir.join("life.toml");
+        fs::write(&config_path, format!("initial_pattern = {:?}\nwidth = 40\nheight = 20\n", pattern_path.display().to_string())).unwrap();
+        let config = Config::from_file(&config_path).unwrap();
+        let board = load_centered_pattern(pattern_path.as_path(), config.board_width() as usize, config.board_height() as usize).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!((10, 5), (board.width(), board.height()));
+        assert_eq!(5, board.count_alive());
+        assert_eq!(Ok(true), board.is_cell_alive(4, 1));
+        assert_eq!(Ok(true), board.is_cell_alive(5, 2));
+        assert!(Config::from_file(&config_path).is_err(), "Missing files should be an error");
+    }
+
+    #[test]
+    fn test_equivalence_sim_stats_rolling_step_rate() {
+        let start = Instant::now();
+        let mut stats = SimStats::new(0, 5);
+        stats.record_step(start, 6);
+        for step in 1..=STEP_RATE_WINDOW as u64 {
+            stats.record_step(start + Duration::from_millis(step * 100), 7);
+        }
+        assert_eq!((61, 7), (stats.generation, stats.population));
+        assert!((stats.actual_step_rate - 10.0).abs() < 1e-9, "{}", stats.actual_step_rate);
+        // Once the window is full, older intervals fall out of the average.
+        let last = start + Duration::from_millis(STEP_RATE_WINDOW as u64 * 100);
+        for step in 1..=STEP_RATE_WINDOW as u64 {
+            stats.record_step(last + Duration::from_millis(step * 50), 7);
+        }
+        assert!((stats.actual_step_rate - 20.0).abs() < 1e-9, "{}", stats.actual_step_rate);
+        stats.record_frame(start);
+        stats.record_frame(start + Duration::from_millis(40));
+        assert_eq!("Life | Gen 121 | Pop 7 | 20.0 steps/s | 25.0 fps", stats.title());
+    }
+
+    #[test]
+    fn test_boundary_step_ms_adjustment_is_clamped() {
+        assert_eq!((125, 500), (faster_step_ms(250), slower_step_ms(250)));
+        assert_eq!(1, faster_step_ms(1));
+        assert_eq!(10_000, slower_step_ms(8_000));
+    }
+
+    #[test]
+    fn test_boundary_cycle_pattern_idx_wraps() {
+        assert_eq!((0, 2), (cycle_pattern_idx(None, 3, true), cycle_pattern_idx(None, 3, false)));
+        assert_eq!((0, 2), (cycle_pattern_idx(Some(2), 3, true), cycle_pattern_idx(Some(0), 3, false)));
+        assert_eq!(1, cycle_pattern_idx(Some(0), 3, true));
+    }
+}
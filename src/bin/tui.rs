@@ -0,0 +1,108 @@
+use std::io;
+use std::io::Write;
+use std::time::{Duration, Instant};
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::style::{Color, Print, ResetColor, SetForegroundColor};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use life::{BaseLifeBoard, Cell, LifeBoard};
+
+const ALIVE_GLYPH: char = '■';  // Printed for alive cells
+const DEAD_GLYPH: char = '·';  // Printed for dead cells
+const ALIVE_COLOR: Color = Color::Cyan;  // Alive cell color
+const DEAD_COLOR: Color = Color::DarkGrey;  // Dead cell color
+const DEFAULT_MS_TIME_STEP: u64 = 250;  // Separation between generations when in auto mode
+
+fn main() -> io::Result<()> {
+    let time_step = match std::env::args().nth(1) {
+        Some(arg) => arg.parse().map(Duration::from_millis).map_err(|_|
+            io::Error::new(io::ErrorKind::InvalidInput, format!("Expected a delay in milliseconds but found \"{arg}\"."))
+        )?,
+        None => Duration::from_millis(DEFAULT_MS_TIME_STEP),
+    };
+    let (columns, rows) = terminal::size()?;
+    // The last terminal row is kept free for the controls line.
+    let mut game = BaseLifeBoard::gen(
+        (columns as usize).max(1),
+        (rows as usize).saturating_sub(1).max(1),
+        Cell::gen
+    );
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(stdout, EnterAlternateScreen, Hide)?;
+    let result = run(&mut game, &mut stdout, time_step);
+    execute!(stdout, Show, LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+fn run(game: &mut BaseLifeBoard<Cell>, stdout: &mut impl Write, time_step: Duration) -> io::Result<()> {
+    let mut auto_step = false;
+    let mut last_frame_time = Instant::now();
+    render_board_to_terminal(game, stdout)?;
+    loop {
+        let timeout = if auto_step { time_step.saturating_sub(last_frame_time.elapsed()) } else { time_step };
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match key.code {
+                    KeyCode::Char('q') => return Ok(()),
+                    KeyCode::Char(' ') => {
+                        game.simulate();
+                        render_board_to_terminal(game, stdout)?;
+                    },
+                    KeyCode::Char('p') => auto_step = !auto_step,
+                    _ => (),
+                }
+            }
+        }
+        if auto_step && last_frame_time.elapsed() >= time_step {
+            last_frame_time = Instant::now();
+            game.simulate();
+            render_board_to_terminal(game, stdout)?;
+        }
+    }
+}
+
+fn render_board_to_terminal(board: &impl LifeBoard<Cell>, stdout: &mut impl Write) -> io::Result<()> {
+    queue!(stdout, MoveTo(0, 0), Clear(ClearType::All))?;
+    for y in 0..board.height() {
+        queue!(stdout, MoveTo(0, y as u16))?;
+        let mut current_color = None;
+        for x in 0..board.width() {
+            let alive = board.is_cell_alive(x, y).expect("Should always be valid indices");
+            let (glyph, color) = if alive { (ALIVE_GLYPH, ALIVE_COLOR) } else { (DEAD_GLYPH, DEAD_COLOR) };
+            if current_color != Some(color) {
+                queue!(stdout, SetForegroundColor(color))?;
+                current_color = Some(color);
+            }
+            queue!(stdout, Print(glyph))?;
+        }
+    }
+    queue!(stdout, ResetColor, MoveTo(0, board.height() as u16), Print("Space: step  p: auto-step  q: quit"))?;
+    stdout.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use life::{BaseLifeBoard, BitBoard, Cell};
+    use crate::{render_board_to_terminal, ALIVE_GLYPH, DEAD_GLYPH};
+
+    #[test]
+    fn test_equivalence_render_board_to_terminal_3x3_board() {
+        let board = BaseLifeBoard::from_rle("x = 3, y = 3\nbo$2bo$3o!", Cell::from_bool).unwrap();
+        let mut output = Vec::<u8>::new();
+        render_board_to_terminal(&board, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(5, output.matches(ALIVE_GLYPH).count());
+        assert_eq!(4, output.matches(DEAD_GLYPH).count());
+        assert!(output.starts_with("\u{1b}[1;1H\u{1b}[2J"), "Frame should start by clearing the screen");
+        assert!(output.contains("\u{1b}[2;1H"), "Each row should start on its own line");
+        let mut bit_board_output = Vec::<u8>::new();
+        render_board_to_terminal(&BitBoard::from_board(&board), &mut bit_board_output).unwrap();
+        assert_eq!(output, String::from_utf8(bit_board_output).unwrap());
+    }
+}
@@ -0,0 +1,63 @@
+use std::fmt::Display;
+use std::io;
+use std::io::Write;
+use crate::life_implementation::Cell;
+use crate::life_interface::LifeBoard;
+
+pub fn run_headless(board: &mut impl LifeBoard<Cell>, steps: usize) {
+    board.simulate_n_steps(steps);
+}
+
+// Writes the starting board and every following generation, separated by a generation header.
+pub fn run_headless_printing<B, W>(board: &mut B, steps: usize, output: &mut W) -> io::Result<()>
+    where
+        B: LifeBoard<Cell> + Display,
+        W: Write
+{
+    writeln!(output, "Generation 0:\n{board}")?;
+    for generation in 1..=steps {
+        board.simulate();
+        writeln!(output, "Generation {generation}:\n{board}")?;
+    }
+    output.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::headless::{run_headless, run_headless_printing};
+    use crate::life_bitboard::BitBoard;
+    use crate::life_implementation::{BaseLifeBoard, Cell, ParallelLifeBoard};
+    use crate::life_interface::LifeBoard;
+    use crate::patterns;
+
+    fn get_blinker_board() -> BaseLifeBoard<Cell> {
+        let mut board = patterns::blinker(Cell::from_bool);
+        board.resize(3, 5, false).unwrap();
+        board.translate(1, 1, false);
+        board
+    }
+
+    #[test]
+    fn test_equivalence_run_headless_blinker_3_steps() {
+        let mut board = ParallelLifeBoard::from_board(get_blinker_board(), 2);
+        run_headless(&mut board, 3);
+        let mut expected_board = get_blinker_board();
+        expected_board.simulate();
+        assert_eq!(ParallelLifeBoard::from_board(expected_board, 2), board);
+    }
+
+    #[test]
+    fn test_equivalence_run_headless_printing_every_generation() {
+        let mut expected_board = get_blinker_board();
+        expected_board.simulate();
+        let mut board = BitBoard::from_board(&get_blinker_board());
+        let mut output = Vec::<u8>::new();
+        run_headless_printing(&mut board, 3, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        for generation in 0..=3 {
+            assert!(output.contains(&format!("Generation {generation}:\n")), "{output}");
+        }
+        assert!(!output.contains("Generation 4"), "{output}");
+        assert_eq!(BitBoard::from_board(&expected_board), board);
+    }
+}
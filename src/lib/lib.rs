@@ -1,5 +1,50 @@
 mod life_implementation;
 mod life_interface;
+mod life_format;
+mod life_bitboard;
+mod life_dead_zone;
+mod life_flat;
+mod life_frontier;
+mod life_quadtree;
+mod life_sparse;
+mod life_hashlife;
+mod life_replay;
+mod life_stats;
+mod life_builder;
+pub mod patterns;
+pub mod headless;
+#[cfg(feature = "serde")]
+mod life_session;
+#[cfg(feature = "image")]
+mod life_image;
+#[cfg(feature = "wasm")]
+mod life_wasm;
+#[cfg(feature = "ffi")]
+pub mod life_ffi;
+#[cfg(feature = "rayon")]
+mod life_rayon;
+#[cfg(feature = "bitvec")]
+mod life_bitvec;
+#[cfg(test)]
+mod test_fixtures;
 
-pub use life_interface::{LifeBoard, LifeCell, LifeBoardError};
-pub use life_implementation::{ParallelLifeBoard, BaseLifeBoard, Cell};
\ No newline at end of file
+pub use life_interface::{BoardFormatter, CellEvent, LifeBoard, LifeCell, LifeBoardError, Point, PopulationStatistics, StepStats};
+pub use life_implementation::{ParallelLifeBoard, BaseLifeBoard, BoolOp, Cell, Neighborhood, Rows, SplitAxis};
+pub use life_bitboard::BitBoard;
+pub use life_dead_zone::DeadZoneOptimizedBoard;
+pub use life_flat::FlatLifeBoard;
+pub use life_frontier::FrontierLifeBoard;
+pub use life_quadtree::QuadTree;
+pub use life_sparse::SparseLifeBoard;
+pub use life_hashlife::HashLifeBoard;
+pub use life_replay::Replay;
+pub use life_stats::{write_population_csv, StatsRecorder};
+pub use life_builder::{Boundary, LifeBoardBuilder};
+#[cfg(feature = "gif")]
+pub use life_image::export_gif;
+#[cfg(feature = "serde")]
+pub use life_session::Session;
+#[cfg(feature = "wasm")]
+pub use life_wasm::WasmLifeBoard;
+#[cfg(feature = "rayon")]
+pub use life_rayon::RayonLifeBoard;
@@ -0,0 +1,298 @@
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use crate::life_implementation::{BaseLifeBoard, Cell};
+use crate::life_interface;
+use crate::life_interface::{LifeBoard, LifeBoardError, LifeCell, StepStats};
+
+const WORD_BITS: usize = u64::BITS as usize;
+static ALIVE_CELL: Cell = Cell::from_bool(true);
+static DEAD_CELL: Cell = Cell::from_bool(false);
+
+#[derive(PartialEq, Clone)]
+pub struct BitBoard {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    wrap_x: bool,
+    wrap_y: bool,
+} impl BitBoard {
+    fn empty(width: usize, height: usize) -> BitBoard {
+        let words_per_row = width.div_ceil(WORD_BITS);
+        BitBoard { words: vec![0; words_per_row * height], width, height, words_per_row, wrap_x: false, wrap_y: false }
+    }
+
+    pub fn from_bool_matrix<A, B>(collection: A) -> Result<BitBoard, LifeBoardError>
+        where
+            A: IntoIterator<Item=B>,
+            B: IntoIterator<Item=bool>
+    {
+        BaseLifeBoard::from_bool_matrix(collection, Cell::from_bool).map(|board| BitBoard::from_board(&board))
+    }
+
+    pub fn from_board<T: LifeCell>(board: &BaseLifeBoard<T>) -> BitBoard {
+        let mut bit_board = BitBoard::empty(board.width(), board.height());
+        (bit_board.wrap_x, bit_board.wrap_y) = (board.wrap_x(), board.wrap_y());
+        for x in 0..board.width() {
+            for y in 0..board.height() {
+                if board.is_cell_alive(x, y) == Ok(true) {
+                    bit_board._set(x, y, true);
+                }
+            }
+        }
+        bit_board
+    }
+
+    pub fn gen(width: usize, height: usize) -> BitBoard {
+        BitBoard::from_board(&BaseLifeBoard::gen(width, height, Cell::gen))
+    }
+
+    pub fn to_base_board(&self) -> BaseLifeBoard<Cell> {
+        let mut board = BaseLifeBoard::from_bool_matrix(
+            (0..self.width).map(|x| (0..self.height).map(move |y| self._get(x, y))),
+            Cell::from_bool
+        ).expect("Should always have valid dimensions");
+        board.set_wrap(self.wrap_x, self.wrap_y);
+        board
+    }
+
+    fn _index(&self, x: usize, y: usize) -> (usize, u64) {
+        (y * self.words_per_row + x / WORD_BITS, 1 << (x % WORD_BITS))
+    }
+
+    fn _get(&self, x: usize, y: usize) -> bool {
+        let (word, bit) = self._index(x, y);
+        self.words[word] & bit != 0
+    }
+
+    fn _set(&mut self, x: usize, y: usize, alive: bool) {
+        let (word, bit) = self._index(x, y);
+        if alive { self.words[word] |= bit } else { self.words[word] &= !bit }
+    }
+
+    fn _row(&self, y: usize) -> &[u64] {
+        &self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    fn _last_word_mask(&self) -> u64 {
+        match self.width % WORD_BITS {
+            0 => u64::MAX,
+            bits => (1 << bits) - 1,
+        }
+    }
+
+    // Returns the (west, center, east) neighbor masks of word `w` so that bit i of each mask holds
+    // the state of the cell at x-1, x and x+1 respectively.
+    fn _shifted(&self, row: &[u64], w: usize) -> [u64; 3] {
+        let center = row[w];
+        let prev = if w > 0 { row[w - 1] } else { 0 };
+        let next = row.get(w + 1).copied().unwrap_or(0);
+        let [mut west, center, mut east] = [(center << 1) | (prev >> (WORD_BITS - 1)), center, (center >> 1) | (next << (WORD_BITS - 1))];
+        // Across a wrapping left/right edge, the first and last cell of the row are each other's neighbors.
+        if self.wrap_x {
+            let last_x = self.width - 1;
+            if w == 0 {
+                west |= (row[last_x / WORD_BITS] >> (last_x % WORD_BITS)) & 1;
+            }
+            if w + 1 == self.words_per_row {
+                east = (east & !(1 << (last_x % WORD_BITS))) | ((row[0] & 1) << (last_x % WORD_BITS));
+            }
+        }
+        [west, center, east]
+    }
+
+    fn _neighbor_row(&self, y: usize, dy: i64) -> Option<&[u64]> {
+        life_interface::wrapped_coordinate(y as i64 + dy, self.height, self.wrap_y).map(|ny| self._row(ny))
+    }
+
+    fn _next_word(alive: u64, neighbors: [u64; 8]) -> u64 {
+        let (mut ones, mut twos, mut fours) = (0u64, 0u64, 0u64);
+        for neighbor in neighbors {
+            let ones_carry = ones & neighbor;
+            ones ^= neighbor;
+            let twos_carry = twos & ones_carry;
+            twos ^= ones_carry;
+            fours |= twos_carry;
+        }
+        twos & !fours & (ones | alive)
+    }
+} impl LifeBoard<Cell> for BitBoard {
+    fn width(&self) -> usize { self.width }
+
+    fn height(&self) -> usize { self.height }
+
+    fn simulate(&mut self) {
+        self.simulate_stats();
+    }
+
+    fn simulate_stats(&mut self) -> StepStats {
+        let empty_row = vec![0u64; self.words_per_row];
+        let last_word_mask = self._last_word_mask();
+        let mut new_words = Vec::with_capacity(self.words.len());
+        for y in 0..self.height {
+            let above = self._neighbor_row(y, -1).unwrap_or(&empty_row);
+            let row = self._row(y);
+            let below = self._neighbor_row(y, 1).unwrap_or(&empty_row);
+            for w in 0..self.words_per_row {
+                let [north_west, north, north_east] = self._shifted(above, w);
+                let [west, center, east] = self._shifted(row, w);
+                let [south_west, south, south_east] = self._shifted(below, w);
+                let mut word = BitBoard::_next_word(
+                    center,
+                    [north_west, north, north_east, west, east, south_west, south, south_east]
+                );
+                if w + 1 == self.words_per_row {
+                    word &= last_word_mask;
+                }
+                new_words.push(word);
+            }
+        }
+        let mut stats = StepStats::default();
+        for (new, old) in new_words.iter().zip(&self.words) {
+            stats.births += (new & !old).count_ones() as usize;
+            stats.deaths += (!new & old).count_ones() as usize;
+            stats.survivals += (new & old).count_ones() as usize;
+        }
+        self.words = new_words;
+        stats
+    }
+
+    fn next_cell_state_at(&self, x: usize, y: usize) -> Result<Cell, LifeBoardError> {
+        let alive = self.cell_at(x, y)?.is_alive();
+        let alive = matches!((alive, self.num_alive_neighbors_at(x, y)?), (true, 2) | (_, 3));
+        Ok(Cell::from_bool(alive))
+    }
+
+    fn cell_at(&self, x: usize, y: usize) -> Result<&Cell, LifeBoardError> {
+        self.is_cell_alive(x, y).map(|alive| if alive { &ALIVE_CELL } else { &DEAD_CELL })
+    }
+
+    fn num_alive_neighbors_at(&self, x: usize, y: usize) -> Result<u8, LifeBoardError> {
+        self.cell_at(x, y)?;
+        let mut neighbors = 0u8;
+        for dx in -1..=1 {
+            let Some(nx) = life_interface::wrapped_coordinate(x as i64 + dx, self.width, self.wrap_x) else { continue };
+            for dy in -1..=1 {
+                match life_interface::wrapped_coordinate(y as i64 + dy, self.height, self.wrap_y) {
+                    Some(ny) if (dx, dy) != (0, 0) => neighbors += self._get(nx, ny) as u8,
+                    _ => (),
+                }
+            }
+        }
+        Ok(neighbors)
+    }
+
+    fn is_cell_alive(&self, x: usize, y: usize) -> Result<bool, LifeBoardError> {
+        if x < self.width && y < self.height {
+            Ok(self._get(x, y))
+        } else {
+            Err(LifeBoardError::InvalidIndex(format!("Cell ({x}, {y}) is outside of the {}x{} board.", self.width, self.height)))
+        }
+    }
+
+    fn to_vec_matrix(&self) -> Vec<Vec<Cell>> {
+        (0..self.width).map(|x|
+            (0..self.height).map(|y| Cell::from_bool(self._get(x, y))).collect()
+        ).collect()
+    }
+
+    fn fill_region(&mut self, x: usize, y: usize, w: usize, h: usize, alive: bool) -> Result<(), LifeBoardError> {
+        let (x_range, y_range) = life_interface::region_ranges(self.width, self.height, x, y, w, h)?;
+        for y in y_range {
+            for x in x_range.clone() {
+                self._set(x, y, alive);
+            }
+        }
+        Ok(())
+    }
+
+    fn count_alive(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+} impl Display for BitBoard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.to_base_board(), f)
+    }
+} impl Debug for BitBoard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.to_base_board(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life_bitboard::BitBoard;
+    use crate::life_implementation::{BaseLifeBoard, Cell};
+    use crate::life_interface::{LifeBoard, LifeBoardError};
+    use crate::test_fixtures;
+    use crate::test_fixtures::{get_7x7_end_board_10th_gen, get_7x7_start_board_0th_gen};
+
+    fn assert_matches_base_board(base_board: BaseLifeBoard<Cell>, steps: usize) {
+        let bit_board = BitBoard::from_board(&base_board);
+        test_fixtures::assert_matches_base_board(base_board, bit_board, steps, BitBoard::to_base_board);
+    }
+
+    #[test]
+    fn test_equivalence_bit_board_simulate_3x3_board() {
+        let mut board = BitBoard::from_bool_matrix([
+            [true, true, true],
+            [false, true, false],
+            [true, false, false]
+        ]).unwrap();
+        board.simulate();
+        let expected_board = BitBoard::from_bool_matrix([
+            [true, true, true],
+            [false, false, true],
+            [false, false, false]
+        ]).unwrap();
+        assert_eq!(expected_board, board);
+    }
+
+    #[test]
+    fn test_equivalence_bit_board_simulate_7x7_board_10_steps() {
+        let mut board = BitBoard::from_board(&get_7x7_start_board_0th_gen());
+        board.simulate_n_steps(10);
+        assert_eq!(BitBoard::from_board(&get_7x7_end_board_10th_gen()), board);
+    }
+
+    #[test]
+    fn test_equivalence_bit_board_matches_base_board_across_word_boundaries() {
+        for width in [1, 7, 63, 64, 65, 130] {
+            assert_matches_base_board(BaseLifeBoard::gen(width, 17, Cell::gen), 20);
+        }
+    }
+
+    #[test]
+    fn test_equivalence_bit_board_matches_base_board_wrapped() {
+        for (wrap_x, wrap_y) in [(true, true), (true, false), (false, true)] {
+            for (width, height) in [(1, 1), (2, 5), (6, 6), (64, 3), (65, 9)] {
+                let mut board = BaseLifeBoard::gen(width, height, Cell::gen);
+                board.set_wrap(wrap_x, wrap_y);
+                assert_matches_base_board(board.clone(), 15);
+                let bit_board = BitBoard::from_board(&board);
+                for (x, y) in [(0, 0), (width - 1, height - 1), (width / 2, 0)] {
+                    assert_eq!(board.num_alive_neighbors_at(x, y), bit_board.num_alive_neighbors_at(x, y));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_equivalence_bit_board_cell_queries() {
+        let base_board = BaseLifeBoard::gen(70, 9, Cell::gen);
+        let bit_board = BitBoard::from_board(&base_board);
+        assert_eq!(base_board.count_alive(), bit_board.count_alive());
+        assert_eq!(base_board.to_vec_matrix(), bit_board.to_vec_matrix());
+        for x in 0..70 {
+            for y in 0..9 {
+                assert_eq!(base_board.num_alive_neighbors_at(x, y).unwrap(), bit_board.num_alive_neighbors_at(x, y).unwrap());
+                assert_eq!(base_board.next_cell_state_at(x, y).unwrap(), bit_board.next_cell_state_at(x, y).unwrap());
+            }
+        }
+        assert!(matches!(bit_board.is_cell_alive(70, 0), Err(LifeBoardError::InvalidIndex(_))), "Cell should be invalid");
+        assert!(bit_board.cell_at(0, 9).is_err(), "Cell should be invalid");
+        assert!(bit_board.num_alive_neighbors_at(70, 0).is_err(), "Center cell should be invalid");
+        assert!(bit_board.next_cell_state_at(70, 0).is_err(), "Center cell should be invalid");
+        assert_eq!(base_board.cell_at(3, 4).unwrap(), bit_board.cell_at(3, 4).unwrap());
+    }
+}
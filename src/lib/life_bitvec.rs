@@ -0,0 +1,53 @@
+use bitvec::prelude::{BitVec, Lsb0};
+use crate::life_implementation::BaseLifeBoard;
+use crate::life_interface::{LifeBoard, LifeBoardError, LifeCell};
+
+impl<T: LifeCell> BaseLifeBoard<T> {
+    // Packs the board row by row, so bit `y * width + x` is the cell at `(x, y)`.
+    pub fn to_bitvec(&self) -> BitVec<u64, Lsb0> {
+        let mut bits = BitVec::with_capacity(self.width() * self.height());
+        bits.extend(self.to_flat());
+        bits
+    }
+
+    pub fn from_bitvec(bits: &BitVec<u64, Lsb0>, width: usize, height: usize, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        if bits.len() != width * height {
+            return Err(LifeBoardError::InvalidBoard(
+                format!("{} bits cannot fill a {width}x{height} board.", bits.len())
+            ));
+        }
+        BaseLifeBoard::from_bool_matrix((0..width).map(|x| (0..height).map(move |y| bits[y * width + x])), init)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitvec::prelude::{BitVec, Lsb0};
+    use crate::life_implementation::{BaseLifeBoard, Cell};
+    use crate::life_interface::{LifeBoard, LifeBoardError};
+
+    #[test]
+    fn test_equivalence_to_bitvec_layout() {
+        let board = BaseLifeBoard::gen_seeded(13, 7, 0.4, 5, Cell::from_bool);
+        let bits = board.to_bitvec();
+        assert_eq!(13 * 7, bits.len());
+        for (x, y) in (0..13).flat_map(|x| (0..7).map(move |y| (x, y))) {
+            assert_eq!(board.is_cell_alive(x, y).unwrap(), bits[y * 13 + x], "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn test_equivalence_bitvec_round_trip() {
+        let board = BaseLifeBoard::gen_seeded(70, 3, 0.5, 8, Cell::from_bool);
+        assert_eq!(board, BaseLifeBoard::from_bitvec(&board.to_bitvec(), 70, 3, Cell::from_bool).unwrap());
+    }
+
+    #[test]
+    fn test_exception_from_bitvec_wrong_length() {
+        let bits: BitVec<u64, Lsb0> = BitVec::repeat(false, 10);
+        match BaseLifeBoard::from_bitvec(&bits, 3, 3, Cell::from_bool) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("10 bits"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+    }
+}
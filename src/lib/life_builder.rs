@@ -0,0 +1,170 @@
+use crate::life_implementation::{BaseLifeBoard, Neighborhood, ParallelLifeBoard};
+use crate::life_interface::{LifeBoardError, LifeCell};
+
+const DEFAULT_RULE: &str = "B3/S23";
+const DEFAULT_DENSITY: f64 = 0.5;
+
+// What lies past the edges of the board: dead cells, or the opposite edge along the wrapping axes.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "snake_case"))]
+pub enum Boundary {
+    #[default]
+    Dead,
+    WrapX,
+    WrapY,
+    #[cfg_attr(feature = "serde", serde(rename = "toroidal"))]
+    Torus,
+} impl Boundary {
+    pub fn from_wrap(wrap_x: bool, wrap_y: bool) -> Boundary {
+        match (wrap_x, wrap_y) {
+            (false, false) => Boundary::Dead,
+            (true, false) => Boundary::WrapX,
+            (false, true) => Boundary::WrapY,
+            (true, true) => Boundary::Torus,
+        }
+    }
+
+    pub fn wrap(&self) -> (bool, bool) {
+        match self {
+            Boundary::Dead => (false, false),
+            Boundary::WrapX => (true, false),
+            Boundary::WrapY => (false, true),
+            Boundary::Torus => (true, true),
+        }
+    }
+}
+
+// Collects the settings of a random board in one chain. Only the size has to be set; the rest falls back to
+// B3/S23 on a Moore neighborhood with a dead boundary, half of the cells alive and a seed from the thread RNG.
+#[derive(Clone, Debug)]
+pub struct LifeBoardBuilder<T: LifeCell> {
+    init: fn(state: bool) -> T,
+    width: usize,
+    height: usize,
+    rule: String,
+    neighborhood: Neighborhood,
+    boundary: Boundary,
+    seed: Option<u64>,
+    density: f64,
+} impl<T: LifeCell> LifeBoardBuilder<T> {
+    pub fn new(init: fn(state: bool) -> T) -> LifeBoardBuilder<T> {
+        LifeBoardBuilder {
+            init, width: 0, height: 0, rule: String::from(DEFAULT_RULE), neighborhood: Neighborhood::default(),
+            boundary: Boundary::default(), seed: None, density: DEFAULT_DENSITY,
+        }
+    }
+
+    pub fn width(mut self, width: usize) -> LifeBoardBuilder<T> {
+        self.width = width;
+        self
+    }
+
+    pub fn height(mut self, height: usize) -> LifeBoardBuilder<T> {
+        self.height = height;
+        self
+    }
+
+    // Only B3/S23 is simulated, so any other rule fails in `build`.
+    pub fn rule(mut self, rule: &str) -> LifeBoardBuilder<T> {
+        self.rule = String::from(rule);
+        self
+    }
+
+    pub fn neighborhood(mut self, neighborhood: Neighborhood) -> LifeBoardBuilder<T> {
+        self.neighborhood = neighborhood;
+        self
+    }
+
+    pub fn boundary(mut self, boundary: Boundary) -> LifeBoardBuilder<T> {
+        self.boundary = boundary;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> LifeBoardBuilder<T> {
+        self.seed = Some(seed);
+        self
+    }
+
+    // Clamped to [0, 1] like `BaseLifeBoard::gen_with_density`.
+    pub fn density(mut self, density: f64) -> LifeBoardBuilder<T> {
+        self.density = density;
+        self
+    }
+
+    pub fn build(&self) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        if !matches!(self.rule.as_str(), "B3/S23" | "23/3") {
+            return Err(LifeBoardError::UnsupportedRule(format!("Builder rule \"{}\" is not B3/S23.", self.rule)));
+        }
+        if self.width == 0 {
+            return Err(LifeBoardError::InvalidBoard(String::from("Board must be at least one cell wide.")));
+        }
+        if self.height == 0 {
+            return Err(LifeBoardError::InvalidBoard(String::from("Board must be at least one cell tall.")));
+        }
+        let mut board = match self.seed {
+            Some(seed) => BaseLifeBoard::gen_seeded(self.width, self.height, self.density, seed, self.init),
+            None => BaseLifeBoard::gen_with_density(self.width, self.height, self.density, self.init),
+        };
+        board.set_neighborhood(self.neighborhood)?;
+        let (wrap_x, wrap_y) = self.boundary.wrap();
+        board.set_wrap(wrap_x, wrap_y);
+        Ok(board)
+    }
+
+    pub fn build_parallel(&self, n_threads: u8) -> Result<ParallelLifeBoard<T>, LifeBoardError> where T: Send + Sync {
+        let mut board = ParallelLifeBoard::from_board(self.build()?, 1);
+        board.set_thread_count(n_threads)?;
+        Ok(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life_builder::{Boundary, LifeBoardBuilder};
+    use crate::life_implementation::{BaseLifeBoard, Cell, Neighborhood};
+    use crate::life_interface::{LifeBoard, LifeBoardError};
+
+    #[test]
+    fn test_equivalence_builder_set_and_default_fields() {
+        let board = LifeBoardBuilder::new(Cell::from_bool).width(30).height(20).seed(9).density(0.3).build().unwrap();
+        assert_eq!(BaseLifeBoard::gen_seeded(30, 20, 0.3, 9, Cell::from_bool), board);
+        assert_eq!((Neighborhood::Moore, false, false), (board.neighborhood(), board.wrap_x(), board.wrap_y()));
+
+        let board = LifeBoardBuilder::new(Cell::from_bool)
+            .height(5).width(8).rule("23/3").boundary(Boundary::WrapY).neighborhood(Neighborhood::VonNeumann)
+            .build().unwrap();
+        assert_eq!((8, 5), (board.width(), board.height()));
+        assert_eq!((Neighborhood::VonNeumann, false, true), (board.neighborhood(), board.wrap_x(), board.wrap_y()));
+    }
+
+    #[test]
+    fn test_equivalence_builder_build_parallel() {
+        let builder = LifeBoardBuilder::new(Cell::from_bool).width(12).height(12).seed(4).boundary(Boundary::Torus);
+        let mut board = builder.build().unwrap();
+        let mut parallel_board = builder.build_parallel(3).unwrap();
+        assert_eq!((3, true, true), (parallel_board.n_threads(), parallel_board.wrap_x(), parallel_board.wrap_y()));
+        board.simulate_n_steps(5);
+        parallel_board.simulate_n_steps(5);
+        assert_eq!(board, parallel_board.into_board());
+    }
+
+    #[test]
+    fn test_exception_builder_invalid_settings() {
+        match LifeBoardBuilder::new(Cell::from_bool).width(4).build() {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("tall"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+        match LifeBoardBuilder::new(Cell::from_bool).width(4).height(4).rule("B36/S23").build() {
+            Err(LifeBoardError::UnsupportedRule(error)) => assert!(error.contains("B36/S23"), "{error}"),
+            result => panic!("Expected an UnsupportedRule error but found {result:?}"),
+        }
+        match LifeBoardBuilder::new(Cell::from_bool).width(4).height(4).neighborhood(Neighborhood::MooreRadius(9)).build() {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("radius 9"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+        match LifeBoardBuilder::new(Cell::from_bool).width(4).height(4).build_parallel(0) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("thread"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+    }
+}
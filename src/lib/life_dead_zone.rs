@@ -0,0 +1,205 @@
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use crate::life_implementation::BaseLifeBoard;
+use crate::life_interface;
+use crate::life_interface::{LifeBoard, LifeBoardError, LifeCell, StepStats};
+
+// Only cells that are alive or within one neighborhood radius of a live cell can change state, so
+// `simulate` skips everything outside of `active`.
+#[derive(Clone)]
+pub struct DeadZoneOptimizedBoard<T: LifeCell> {
+    board: BaseLifeBoard<T>,
+    active: Vec<(usize, usize)>,
+    cells_evaluated: usize,
+} impl<T: LifeCell> DeadZoneOptimizedBoard<T> {
+    pub fn from_board(board: BaseLifeBoard<T>) -> DeadZoneOptimizedBoard<T> {
+        let active = DeadZoneOptimizedBoard::_active_region(&board, DeadZoneOptimizedBoard::_live_cells(&board));
+        DeadZoneOptimizedBoard { board, active, cells_evaluated: 0 }
+    }
+
+    fn _live_cells(board: &BaseLifeBoard<T>) -> impl Iterator<Item=(usize, usize)> + '_ {
+        (0..board.width())
+            .flat_map(|x| (0..board.height()).map(move |y| (x, y)))
+            .filter(|&(x, y)| board.is_cell_alive(x, y) == Ok(true))
+    }
+
+    pub fn into_board(self) -> BaseLifeBoard<T> { self.board }
+
+    pub fn active_region(&self) -> &[(usize, usize)] { &self.active }
+
+    pub fn cells_evaluated(&self) -> usize { self.cells_evaluated }
+
+    fn _active_region<I: Iterator<Item=(usize, usize)>>(board: &BaseLifeBoard<T>, live_cells: I) -> Vec<(usize, usize)> {
+        let radius = board.neighborhood().radius();
+        let mut active = Vec::new();
+        for (x, y) in live_cells {
+            for dx in -radius..=radius {
+                // Births across a wrapping edge have to be evaluated too, so the region wraps instead of being clipped.
+                let Some(nx) = life_interface::wrapped_coordinate(x as i64 + dx, board.width(), board.wrap_x()) else { continue };
+                for dy in -radius..=radius {
+                    if let Some(ny) = life_interface::wrapped_coordinate(y as i64 + dy, board.height(), board.wrap_y()) {
+                        active.push((nx, ny));
+                    }
+                }
+            }
+        }
+        active.sort_unstable();
+        active.dedup();
+        active
+    }
+
+    // Growing the board shifts every cell, so the active region is rebuilt from the live cells.
+    fn _apply_auto_expand(&mut self) {
+        if self.board._apply_auto_expand() {
+            self.active = DeadZoneOptimizedBoard::_active_region(&self.board, DeadZoneOptimizedBoard::_live_cells(&self.board));
+        }
+    }
+} impl<T: LifeCell> LifeBoard<T> for DeadZoneOptimizedBoard<T> {
+    fn width(&self) -> usize { self.board.width() }
+
+    fn height(&self) -> usize { self.board.height() }
+
+    fn simulate(&mut self) {
+        self.simulate_stats();
+    }
+
+    fn simulate_stats(&mut self) -> StepStats {
+        self._apply_auto_expand();
+        let mut stats = StepStats::default();
+        let updates: Vec<(usize, usize, T)> = self.active.iter().map(|&(x, y)| {
+            let new_cell = self.board.next_cell_state_at(x, y).expect("Should always access a valid index");
+            (x, y, new_cell)
+        }).collect();
+        self.cells_evaluated = updates.len();
+        let mut live_cells = Vec::new();
+        for (x, y, new_cell) in updates {
+            let was_alive = self.board.is_cell_alive(x, y) == Ok(true);
+            stats.record(was_alive, new_cell.is_alive());
+            if new_cell.is_alive() {
+                live_cells.push((x, y));
+            }
+            self.board._set_cell(x, y, new_cell);
+        }
+        self.active = DeadZoneOptimizedBoard::_active_region(&self.board, live_cells.into_iter());
+        self._apply_auto_expand();
+        stats
+    }
+
+    fn next_cell_state_at(&self, x: usize, y: usize) -> Result<T, LifeBoardError> { self.board.next_cell_state_at(x, y) }
+
+    fn cell_at(&self, x: usize, y: usize) -> Result<&T, LifeBoardError> { self.board.cell_at(x, y) }
+
+    fn num_alive_neighbors_at(&self, x: usize, y: usize) -> Result<u8, LifeBoardError> { self.board.num_alive_neighbors_at(x, y) }
+
+    fn is_cell_alive(&self, x: usize, y: usize) -> Result<bool, LifeBoardError> { self.board.is_cell_alive(x, y) }
+
+    fn to_vec_matrix(&self) -> Vec<Vec<T>> { self.board.to_vec_matrix() }
+
+    fn fill_region(&mut self, x: usize, y: usize, w: usize, h: usize, alive: bool) -> Result<(), LifeBoardError> {
+        self.board.fill_region(x, y, w, h, alive)?;
+        self.active = DeadZoneOptimizedBoard::_active_region(&self.board, DeadZoneOptimizedBoard::_live_cells(&self.board));
+        Ok(())
+    }
+
+    fn count_alive(&self) -> usize {
+        self.active.iter().filter(|&&(x, y)| self.board.is_cell_alive(x, y) == Ok(true)).count()
+    }
+
+    fn is_stable(&self) -> bool {
+        self.active.iter().all(|&(x, y)|
+            self.board.next_cell_state_at(x, y).map(|cell| cell.is_alive()) == self.board.is_cell_alive(x, y)
+        )
+    }
+} impl<T: LifeCell> PartialEq for DeadZoneOptimizedBoard<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.board == other.board
+    }
+} impl<T: LifeCell> Display for DeadZoneOptimizedBoard<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.board, f)
+    }
+} impl<T: LifeCell> Debug for DeadZoneOptimizedBoard<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.board, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life_dead_zone::DeadZoneOptimizedBoard;
+    use crate::life_implementation::{BaseLifeBoard, Cell, Neighborhood};
+    use crate::life_interface::LifeBoard;
+    use crate::test_fixtures;
+
+    const GLIDER_RLE: &str = "bo$2bo$3o!";
+
+    fn get_glider_board(width: usize, height: usize) -> BaseLifeBoard<Cell> {
+        let mut board = BaseLifeBoard::from_rle(&format!("x = 3, y = 3\n{GLIDER_RLE}"), Cell::from_bool).unwrap();
+        board.resize(width, height, false).unwrap();
+        board
+    }
+
+    fn assert_matches_base_board(base_board: BaseLifeBoard<Cell>, steps: usize) {
+        let dead_zone_board = DeadZoneOptimizedBoard::from_board(base_board.clone());
+        test_fixtures::assert_matches_base_board(base_board, dead_zone_board, steps, |board| board.clone().into_board());
+    }
+
+    #[test]
+    fn test_equivalence_dead_zone_matches_base_board() {
+        for (width, height) in [(1, 1), (7, 7), (30, 20), (64, 3)] {
+            assert_matches_base_board(BaseLifeBoard::gen(width, height, Cell::gen), 25);
+        }
+        assert_matches_base_board(get_glider_board(12, 12), 40);
+    }
+
+    #[test]
+    fn test_equivalence_dead_zone_matches_base_board_von_neumann() {
+        let mut board = BaseLifeBoard::gen(20, 20, Cell::gen);
+        board.set_neighborhood(Neighborhood::VonNeumann).unwrap();
+        assert_matches_base_board(board, 10);
+        let mut board = BaseLifeBoard::gen(20, 20, Cell::gen);
+        board.set_neighborhood(Neighborhood::MooreRadius(2)).unwrap();
+        assert_matches_base_board(board, 10);
+    }
+
+    #[test]
+    fn test_equivalence_dead_zone_matches_base_board_wrapped() {
+        for (wrap_x, wrap_y) in [(true, true), (true, false), (false, true)] {
+            let mut board = get_glider_board(6, 6);
+            board.set_wrap(wrap_x, wrap_y);
+            assert_matches_base_board(board, 30);
+            let mut board = BaseLifeBoard::gen(15, 10, Cell::gen);
+            board.set_wrap(wrap_x, wrap_y);
+            assert_matches_base_board(board, 15);
+        }
+    }
+
+    #[test]
+    fn test_equivalence_dead_zone_matches_base_board_auto_expand() {
+        let mut board = get_glider_board(3, 3);
+        board.set_auto_expand(Some(2));
+        assert_matches_base_board(board, 40);
+    }
+
+    #[test]
+    fn test_boundary_dead_zone_glider_work_independent_of_board_size() {
+        let mut small_board = DeadZoneOptimizedBoard::from_board(get_glider_board(20, 20));
+        let mut large_board = DeadZoneOptimizedBoard::from_board(get_glider_board(500, 500));
+        for _ in 0..8 {
+            small_board.simulate();
+            large_board.simulate();
+            assert_eq!(small_board.cells_evaluated(), large_board.cells_evaluated());
+            assert!(large_board.cells_evaluated() <= 25, "Evaluated {} cells", large_board.cells_evaluated());
+        }
+        assert_eq!(5, large_board.count_alive());
+    }
+
+    #[test]
+    fn test_boundary_dead_zone_empty_board_evaluates_nothing() {
+        let mut board = DeadZoneOptimizedBoard::from_board(BaseLifeBoard::from_bool_matrix(vec![vec![false; 50]; 50], Cell::from_bool).unwrap());
+        board.simulate();
+        assert_eq!(0, board.cells_evaluated());
+        assert!(board.active_region().is_empty());
+        assert!(board.is_stable());
+    }
+}
@@ -0,0 +1,174 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+use crate::life_implementation::{BaseLifeBoard, Cell};
+use crate::life_interface::LifeBoard;
+
+pub const LIFE_OK: i32 = 0;
+pub const LIFE_ERROR_NULL_POINTER: i32 = -1;
+pub const LIFE_ERROR_OUT_OF_BOUNDS: i32 = -2;
+pub const LIFE_ERROR_INVALID_ARGUMENT: i32 = -3;
+pub const LIFE_ERROR_PANIC: i32 = -4;
+
+// Opaque to C, which only ever holds a pointer obtained from `life_board_new` or `life_board_from_cells`.
+pub struct LifeHandle {
+    board: BaseLifeBoard<Cell>,
+}
+
+// Panics must not unwind into C, so every entry point reports them as `LIFE_ERROR_PANIC` (or a null handle) instead.
+fn catch_panic<R, F: FnOnce() -> R>(on_panic: R, f: F) -> R {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(on_panic)
+}
+
+fn into_handle(board: BaseLifeBoard<Cell>) -> *mut LifeHandle {
+    Box::into_raw(Box::new(LifeHandle { board }))
+}
+
+/// Creates a random board where each cell is alive with probability `density`. Returns null if either dimension is zero.
+#[no_mangle]
+pub extern "C" fn life_board_new(width: usize, height: usize, density: f64, seed: u64) -> *mut LifeHandle {
+    catch_panic(ptr::null_mut(), || {
+        if width == 0 || height == 0 || width.checked_mul(height).is_none() {
+            return ptr::null_mut();
+        }
+        let mut rng = SmallRng::seed_from_u64(seed);
+        into_handle(BaseLifeBoard::gen_with_rng(width, height, density, &mut rng, Cell::from_bool))
+    })
+}
+
+/// Creates a board from `len` row-major bytes where any non-zero byte is alive. Returns null if `len` is not `width * height`.
+///
+/// # Safety
+/// `cells` must be null or point to at least `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn life_board_from_cells(cells: *const u8, len: usize, width: usize, height: usize) -> *mut LifeHandle {
+    catch_panic(ptr::null_mut(), || {
+        if cells.is_null() || width == 0 || width.checked_mul(height) != Some(len) {
+            return ptr::null_mut();
+        }
+        let cells: Vec<bool> = slice::from_raw_parts(cells, len).iter().map(|&cell| cell != 0).collect();
+        BaseLifeBoard::from_flat(&cells, width, Cell::from_bool).map_or(ptr::null_mut(), into_handle)
+    })
+}
+
+/// # Safety
+/// `handle` must be null or a live pointer returned by this library.
+#[no_mangle]
+pub unsafe extern "C" fn life_board_step(handle: *mut LifeHandle) -> i32 {
+    life_board_step_n(handle, 1)
+}
+
+/// # Safety
+/// `handle` must be null or a live pointer returned by this library.
+#[no_mangle]
+pub unsafe extern "C" fn life_board_step_n(handle: *mut LifeHandle, n: usize) -> i32 {
+    catch_panic(LIFE_ERROR_PANIC, || match handle.as_mut() {
+        Some(handle) => {
+            handle.board.simulate_n_steps(n);
+            LIFE_OK
+        },
+        None => LIFE_ERROR_NULL_POINTER,
+    })
+}
+
+/// Returns 1 if the cell is alive, 0 if it is dead, or a negative error code.
+///
+/// # Safety
+/// `handle` must be null or a live pointer returned by this library.
+#[no_mangle]
+pub unsafe extern "C" fn life_board_get_cell(handle: *const LifeHandle, x: usize, y: usize) -> i32 {
+    catch_panic(LIFE_ERROR_PANIC, || match handle.as_ref() {
+        Some(handle) => match handle.board.is_cell_alive(x, y) {
+            Ok(alive) => alive as i32,
+            Err(_) => LIFE_ERROR_OUT_OF_BOUNDS,
+        },
+        None => LIFE_ERROR_NULL_POINTER,
+    })
+}
+
+/// Writes one row-major byte per cell (1 alive, 0 dead). `len` must be exactly `width * height`.
+///
+/// # Safety
+/// `handle` must be null or a live pointer returned by this library, and `buffer` must be null or point to `len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn life_board_fill_buffer(handle: *const LifeHandle, buffer: *mut u8, len: usize) -> i32 {
+    catch_panic(LIFE_ERROR_PANIC, || {
+        let handle = match handle.as_ref() {
+            Some(handle) if !buffer.is_null() => handle,
+            _ => return LIFE_ERROR_NULL_POINTER,
+        };
+        let width = handle.board.width();
+        if len != width * handle.board.height() {
+            return LIFE_ERROR_INVALID_ARGUMENT;
+        }
+        for (i, byte) in slice::from_raw_parts_mut(buffer, len).iter_mut().enumerate() {
+            *byte = (handle.board.is_cell_alive(i % width, i / width) == Ok(true)) as u8;
+        }
+        LIFE_OK
+    })
+}
+
+/// # Safety
+/// `handle` must be null or a pointer returned by this library that has not already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn life_board_free(handle: *mut LifeHandle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+    use crate::life_ffi::*;
+
+    #[test]
+    fn test_equivalence_ffi_blinker_lifecycle() {
+        let cells = [0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0u8];
+        unsafe {
+            let handle = life_board_from_cells(cells.as_ptr(), cells.len(), 5, 3);
+            assert!(!handle.is_null());
+            assert_eq!((1, 0), (life_board_get_cell(handle, 1, 1), life_board_get_cell(handle, 2, 0)));
+            assert_eq!(LIFE_OK, life_board_step(handle));
+            let mut buffer = [9u8; 15];
+            assert_eq!(LIFE_OK, life_board_fill_buffer(handle, buffer.as_mut_ptr(), buffer.len()));
+            assert_eq!([0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0], buffer);
+            assert_eq!(LIFE_OK, life_board_step_n(handle, 3));
+            assert_eq!((1, 0), (life_board_get_cell(handle, 1, 1), life_board_get_cell(handle, 2, 0)));
+            life_board_free(handle);
+        }
+    }
+
+    #[test]
+    fn test_equivalence_ffi_seeded_boards_match() {
+        unsafe {
+            let (first, second) = (life_board_new(16, 8, 0.5, 42), life_board_new(16, 8, 0.5, 42));
+            assert_eq!(LIFE_OK, life_board_step_n(first, 5));
+            assert_eq!(LIFE_OK, life_board_step_n(second, 5));
+            assert_eq!((*first).board, (*second).board);
+            life_board_free(first);
+            life_board_free(second);
+        }
+    }
+
+    #[test]
+    fn test_exception_ffi_invalid_arguments() {
+        let cells = [1u8; 4];
+        unsafe {
+            assert!(life_board_new(0, 8, 0.5, 1).is_null());
+            assert!(life_board_from_cells(ptr::null(), 4, 2, 2).is_null());
+            assert!(life_board_from_cells(cells.as_ptr(), 4, 3, 2).is_null());
+            assert_eq!(LIFE_ERROR_NULL_POINTER, life_board_step(ptr::null_mut()));
+            assert_eq!(LIFE_ERROR_NULL_POINTER, life_board_get_cell(ptr::null(), 0, 0));
+            let handle = life_board_from_cells(cells.as_ptr(), 4, 2, 2);
+            assert_eq!(LIFE_ERROR_OUT_OF_BOUNDS, life_board_get_cell(handle, 2, 0));
+            assert_eq!(LIFE_ERROR_NULL_POINTER, life_board_fill_buffer(handle, ptr::null_mut(), 4));
+            let mut buffer = [0u8; 3];
+            assert_eq!(LIFE_ERROR_INVALID_ARGUMENT, life_board_fill_buffer(handle, buffer.as_mut_ptr(), buffer.len()));
+            life_board_free(handle);
+            life_board_free(ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use crate::life_implementation::BaseLifeBoard;
+use crate::life_interface;
+use crate::life_interface::{LifeBoard, LifeBoardError, LifeCell, StepStats};
+
+// Stores every cell in a single allocation, row by row, at index `y * width + x`.
+#[derive(PartialEq, Clone)]
+pub struct FlatLifeBoard<T: LifeCell> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    wrap_x: bool,
+    wrap_y: bool,
+} impl<T: LifeCell> FlatLifeBoard<T> {
+    pub fn from_board(board: &BaseLifeBoard<T>) -> FlatLifeBoard<T> {
+        let (width, height) = (board.width(), board.height());
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| board.cell_at(x, y).expect("Should always be valid indices").clone())
+            .collect();
+        FlatLifeBoard { cells, width, height, wrap_x: board.wrap_x(), wrap_y: board.wrap_y() }
+    }
+
+    pub fn gen(width: usize, height: usize, gen: fn() -> T) -> FlatLifeBoard<T> {
+        FlatLifeBoard::from_board(&BaseLifeBoard::gen(width, height, gen))
+    }
+
+    pub fn to_base_board(&self) -> BaseLifeBoard<T> {
+        let mut board = BaseLifeBoard::from_cell_matrix(
+            (0..self.width).map(|x| (0..self.height).map(move |y| self.cells[self._index(x, y)].clone()))
+        ).expect("Should always have valid dimensions");
+        board.set_wrap(self.wrap_x, self.wrap_y);
+        board
+    }
+
+    fn _index(&self, x: usize, y: usize) -> usize { y * self.width + x }
+
+    fn _in_bounds(&self, x: usize, y: usize) -> Result<usize, LifeBoardError> {
+        if x < self.width && y < self.height {
+            Ok(self._index(x, y))
+        } else {
+            Err(LifeBoardError::InvalidIndex(
+                format!("Cell ({x}, {y}) is outside of the {}x{} board.", self.width, self.height)
+            ))
+        }
+    }
+
+    fn _count_neighbors(&self, x: usize, y: usize) -> u8 {
+        if self.wrap_x || self.wrap_y {
+            return self._count_wrapped_neighbors(x, y);
+        }
+        let mut neighbors = 0;
+        let (x_min, x_max) = (x.saturating_sub(1), (x + 1).min(self.width - 1));
+        for ny in y.saturating_sub(1)..=(y + 1).min(self.height - 1) {
+            let row_start = ny * self.width;
+            for cell in &self.cells[row_start + x_min..=row_start + x_max] {
+                neighbors += cell.is_alive() as u8;
+            }
+        }
+        neighbors - self.cells[self._index(x, y)].is_alive() as u8
+    }
+
+    // Visits every offset like `BaseLifeBoard` does, so tiny wrapping boards count a cell once per offset it wraps to.
+    fn _count_wrapped_neighbors(&self, x: usize, y: usize) -> u8 {
+        let mut neighbors = 0;
+        for dx in -1..=1 {
+            let Some(nx) = life_interface::wrapped_coordinate(x as i64 + dx, self.width, self.wrap_x) else { continue };
+            for dy in -1..=1 {
+                match life_interface::wrapped_coordinate(y as i64 + dy, self.height, self.wrap_y) {
+                    Some(ny) if (dx, dy) != (0, 0) => neighbors += self.cells[self._index(nx, ny)].is_alive() as u8,
+                    _ => (),
+                }
+            }
+        }
+        neighbors
+    }
+
+    fn _next_cell(&self, index: usize, neighbors: u8) -> T {
+        let cell = &self.cells[index];
+        match (cell.is_alive(), neighbors) {
+            (true, 2) | (_, 3) => cell.to_alive(),
+            _ => cell.to_dead(),
+        }
+    }
+} impl<T: LifeCell> LifeBoard<T> for FlatLifeBoard<T> {
+    fn width(&self) -> usize { self.width }
+
+    fn height(&self) -> usize { self.height }
+
+    fn simulate(&mut self) {
+        self.simulate_stats();
+    }
+
+    fn simulate_stats(&mut self) -> StepStats {
+        let mut stats = StepStats::default();
+        let mut new_cells = Vec::with_capacity(self.cells.len());
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let index = self._index(x, y);
+                let new_cell = self._next_cell(index, self._count_neighbors(x, y));
+                stats.record(self.cells[index].is_alive(), new_cell.is_alive());
+                new_cells.push(new_cell);
+            }
+        }
+        self.cells = new_cells;
+        stats
+    }
+
+    fn next_cell_state_at(&self, x: usize, y: usize) -> Result<T, LifeBoardError> {
+        let index = self._in_bounds(x, y)?;
+        Ok(self._next_cell(index, self._count_neighbors(x, y)))
+    }
+
+    fn cell_at(&self, x: usize, y: usize) -> Result<&T, LifeBoardError> {
+        self._in_bounds(x, y).map(|index| &self.cells[index])
+    }
+
+    fn num_alive_neighbors_at(&self, x: usize, y: usize) -> Result<u8, LifeBoardError> {
+        self._in_bounds(x, y)?;
+        Ok(self._count_neighbors(x, y))
+    }
+
+    fn is_cell_alive(&self, x: usize, y: usize) -> Result<bool, LifeBoardError> {
+        self._in_bounds(x, y).map(|index| self.cells[index].is_alive())
+    }
+
+    fn to_vec_matrix(&self) -> Vec<Vec<T>> {
+        (0..self.width).map(|x|
+            (0..self.height).map(|y| self.cells[self._index(x, y)].clone()).collect()
+        ).collect()
+    }
+
+    fn fill_region(&mut self, x: usize, y: usize, w: usize, h: usize, alive: bool) -> Result<(), LifeBoardError> {
+        let (x_range, y_range) = life_interface::region_ranges(self.width, self.height, x, y, w, h)?;
+        for y in y_range {
+            let row_start = self._index(0, y);
+            for cell in &mut self.cells[row_start + x_range.start..row_start + x_range.end] {
+                *cell = if alive { cell.to_alive() } else { cell.to_dead() };
+            }
+        }
+        Ok(())
+    }
+
+    fn count_alive(&self) -> usize {
+        self.cells.iter().filter(|cell| cell.is_alive()).count()
+    }
+} impl<T: LifeCell> Display for FlatLifeBoard<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.to_base_board(), f)
+    }
+} impl<T: LifeCell> Debug for FlatLifeBoard<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.to_base_board(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life_flat::FlatLifeBoard;
+    use crate::life_implementation::{BaseLifeBoard, Cell};
+    use crate::life_interface::{LifeBoard, LifeBoardError};
+    use crate::test_fixtures;
+    use crate::test_fixtures::get_7x7_start_board_0th_gen;
+
+    fn assert_matches_base_board(base_board: BaseLifeBoard<Cell>, steps: usize) {
+        let flat_board = FlatLifeBoard::from_board(&base_board);
+        test_fixtures::assert_matches_base_board(base_board, flat_board, steps, FlatLifeBoard::to_base_board);
+    }
+
+    #[test]
+    fn test_equivalence_flat_board_simulate_7x7_board_10_steps() {
+        assert_matches_base_board(get_7x7_start_board_0th_gen(), 10);
+    }
+
+    #[test]
+    fn test_equivalence_flat_board_matches_base_board() {
+        for (width, height) in [(1, 1), (1, 9), (9, 1), (23, 17)] {
+            assert_matches_base_board(BaseLifeBoard::gen(width, height, Cell::gen), 15);
+        }
+    }
+
+    #[test]
+    fn test_equivalence_flat_board_matches_base_board_wrapped() {
+        for (wrap_x, wrap_y) in [(true, true), (true, false), (false, true)] {
+            for (width, height) in [(1, 1), (2, 5), (6, 6), (23, 17)] {
+                let mut board = BaseLifeBoard::gen(width, height, Cell::gen);
+                board.set_wrap(wrap_x, wrap_y);
+                assert_matches_base_board(board, 15);
+            }
+        }
+    }
+
+    #[test]
+    fn test_equivalence_flat_board_cell_queries() {
+        let base_board = get_7x7_start_board_0th_gen();
+        let flat_board = FlatLifeBoard::from_board(&base_board);
+        assert_eq!(base_board.count_alive(), flat_board.count_alive());
+        assert_eq!(base_board.to_vec_matrix(), flat_board.to_vec_matrix());
+        for x in 0..7 {
+            for y in 0..7 {
+                assert_eq!(base_board.num_alive_neighbors_at(x, y).unwrap(), flat_board.num_alive_neighbors_at(x, y).unwrap());
+                assert_eq!(base_board.next_cell_state_at(x, y).unwrap(), flat_board.next_cell_state_at(x, y).unwrap());
+            }
+        }
+        assert!(matches!(flat_board.is_cell_alive(7, 0), Err(LifeBoardError::InvalidIndex(_))), "Cell should be invalid");
+        assert!(flat_board.cell_at(0, 7).is_err(), "Cell should be invalid");
+        assert!(flat_board.num_alive_neighbors_at(7, 7).is_err(), "Center cell should be invalid");
+    }
+}
@@ -0,0 +1,1015 @@
+use crate::life_implementation::BaseLifeBoard;
+use crate::life_interface::{LifeBoard, LifeBoardError, LifeCell};
+
+const SNAPSHOT_MAGIC: &[u8; 4] = b"LIFE";
+const SNAPSHOT_VERSION: u8 = 1;
+const SNAPSHOT_HEADER_LEN: usize = 21;
+const RLE_LINE_LENGTH: usize = 70;
+const RLE_RULE: &str = "B3/S23";
+const PLAINTEXT_ALIVE: char = 'O';
+const PLAINTEXT_DEAD: char = '.';
+const MAX_DECODED_CELLS: usize = 1 << 26;  // Largest board the RLE, snapshot, apgcode and Life 1.06 readers will allocate
+
+pub(crate) fn check_decoded_size(width: usize, height: usize) -> Result<(), String> {
+    if width.checked_mul(height).is_none_or(|n_cells| n_cells > MAX_DECODED_CELLS) {
+        return Err(format!("{width}x{height} cells exceeds the limit of {MAX_DECODED_CELLS} cells"));
+    }
+    Ok(())
+}
+
+fn rle_token(count: usize, tag: char) -> String {
+    if count == 1 { tag.to_string() } else { format!("{count}{tag}") }
+}
+
+pub(crate) fn encode_rle<F: Fn(usize, usize) -> bool>(width: usize, height: usize, is_alive: F) -> String {
+    let mut tokens = Vec::new();
+    let mut pending_rows = 0;
+    for y in 0..height {
+        let mut row_tokens = Vec::new();
+        let mut x = 0;
+        while x < width {
+            let alive = is_alive(x, y);
+            let run_start = x;
+            while x < width && is_alive(x, y) == alive {
+                x += 1;
+            }
+            if alive || x < width {
+                row_tokens.push(rle_token(x - run_start, if alive { 'o' } else { 'b' }));
+            }
+        }
+        if !row_tokens.is_empty() {
+            if pending_rows > 0 {
+                tokens.push(rle_token(pending_rows, '$'));
+            }
+            pending_rows = 0;
+            tokens.extend(row_tokens);
+        }
+        pending_rows += 1;
+    }
+    tokens.push(String::from("!"));
+
+    let mut rle = format!("x = {width}, y = {height}, rule = {RLE_RULE}\n");
+    let mut line_length = 0;
+    for token in tokens {
+        if line_length + token.len() > RLE_LINE_LENGTH {
+            rle.push('\n');
+            line_length = 0;
+        }
+        line_length += token.len();
+        rle.push_str(&token);
+    }
+    rle.push('\n');
+    rle
+}
+
+pub(crate) fn encode_life106<F: Fn(usize, usize) -> bool>(width: usize, height: usize, is_alive: F) -> String {
+    let mut life106 = String::from("#Life 1.06\n");
+    for y in 0..height {
+        for x in (0..width).filter(|&x| is_alive(x, y)) {
+            life106.push_str(&format!("{x} {y}\n"));
+        }
+    }
+    life106
+}
+
+pub(crate) fn encode_csv<F: Fn(usize, usize) -> bool>(width: usize, height: usize, header: bool, is_alive: F) -> String {
+    let mut csv = String::with_capacity((2 * width).max(1) * (height + header as usize));
+    if header {
+        csv.push_str(&(0..width).map(|x| x.to_string()).collect::<Vec<_>>().join(","));
+        csv.push('\n');
+    }
+    for y in 0..height {
+        let row: Vec<&str> = (0..width).map(|x| if is_alive(x, y) { "1" } else { "0" }).collect();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+// Draws each horizontal run of alive cells as a single rect, optionally over a dead background and under grid lines.
+pub(crate) fn encode_svg<F: Fn(usize, usize) -> bool>(
+    width: usize,
+    height: usize,
+    cell_size: f64,
+    alive_fill: &str,
+    dead_fill: Option<&str>,
+    grid_stroke: Option<&str>,
+    is_alive: F,
+) -> String {
+    let (svg_width, svg_height) = (width as f64 * cell_size, height as f64 * cell_size);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{svg_width}\" height=\"{svg_height}\" viewBox=\"0 0 {svg_width} {svg_height}\">\n"
+    );
+    if let Some(dead_fill) = dead_fill {
+        svg.push_str(&format!("<rect width=\"{svg_width}\" height=\"{svg_height}\" fill=\"{}\"/>\n", escape_xml(dead_fill)));
+    }
+    svg.push_str(&format!("<g fill=\"{}\">\n", escape_xml(alive_fill)));
+    for y in 0..height {
+        let mut x = 0;
+        while x < width {
+            if !is_alive(x, y) {
+                x += 1;
+                continue;
+            }
+            let run_start = x;
+            while x < width && is_alive(x, y) {
+                x += 1;
+            }
+            svg.push_str(&format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{cell_size}\"/>\n",
+                run_start as f64 * cell_size, y as f64 * cell_size, (x - run_start) as f64 * cell_size
+            ));
+        }
+    }
+    svg.push_str("</g>\n");
+    if let Some(grid_stroke) = grid_stroke {
+        let vertical = (0..=width).map(|x| format!("M{} 0V{svg_height}", x as f64 * cell_size));
+        let horizontal = (0..=height).map(|y| format!("M0 {}H{svg_width}", y as f64 * cell_size));
+        svg.push_str(&format!(
+            "<path d=\"{}\" fill=\"none\" stroke=\"{}\"/>\n", vertical.chain(horizontal).collect::<String>(), escape_xml(grid_stroke)
+        ));
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn parse_life106(life106: &str) -> Result<Vec<(i64, i64)>, LifeBoardError> {
+    let mut lines = life106.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+    match lines.next() {
+        Some((_, header)) if header.trim() == "#Life 1.06" => (),
+        _ => return Err(LifeBoardError::InvalidBoard(String::from("Life 1.06 pattern must start with a \"#Life 1.06\" header."))),
+    }
+    let mut cells = Vec::new();
+    for (line_idx, line) in lines {
+        let line = line.trim();
+        if line.starts_with('#') {
+            continue;
+        }
+        let coordinates: Vec<Option<i64>> = line.split_whitespace().map(|value| value.parse().ok()).collect();
+        match coordinates[..] {
+            [Some(x), Some(y)] => cells.push((x, y)),
+            _ => return Err(LifeBoardError::InvalidBoard(format!(
+                "Invalid Life 1.06 coordinates on line {}: \"{line}\".", line_idx + 1
+            ))),
+        }
+    }
+    Ok(cells)
+}
+
+const MACROCELL_LEAF_LEVEL: u32 = 3;
+const MACROCELL_LEAF_SIZE: usize = 8;
+
+enum MacroCellNode {
+    Leaf(Vec<(usize, usize)>),
+    Inner(u32, [usize; 4]),
+}
+
+impl MacroCellNode {
+    fn level(&self) -> u32 {
+        match self {
+            MacroCellNode::Leaf(_) => MACROCELL_LEAF_LEVEL,
+            MacroCellNode::Inner(level, _) => *level,
+        }
+    }
+}
+
+fn parse_macrocell_leaf(line: &str) -> Result<Vec<(usize, usize)>, String> {
+    let mut cells = Vec::new();
+    let (mut x, mut y) = (0, 0);
+    for tag in line.chars() {
+        match tag {
+            '.' => x += 1,
+            '*' => {
+                cells.push((x, y));
+                x += 1;
+            },
+            '$' => {
+                x = 0;
+                y += 1;
+            },
+            tag => return Err(format!("unexpected character '{tag}' in leaf")),
+        }
+        if x > MACROCELL_LEAF_SIZE || y > MACROCELL_LEAF_SIZE || (y == MACROCELL_LEAF_SIZE && x > 0) {
+            return Err(String::from("leaf exceeds 8x8 cells"));
+        }
+    }
+    Ok(cells)
+}
+
+fn parse_macrocell_inner(line: &str, nodes: &[MacroCellNode]) -> Result<MacroCellNode, String> {
+    let values: Vec<Option<usize>> = line.split_whitespace().map(|value| value.parse().ok()).collect();
+    let [Some(level), Some(nw), Some(ne), Some(sw), Some(se)] = values[..] else {
+        return Err(String::from("expected a level followed by four node indices"));
+    };
+    let level = match u32::try_from(level) {
+        Ok(level) if level > MACROCELL_LEAF_LEVEL && level < usize::BITS => level,
+        _ => return Err(format!("level {level} is out of range")),
+    };
+    for child in [nw, ne, sw, se] {
+        match child.checked_sub(1).map(|idx| nodes.get(idx)) {
+            None => (),
+            Some(Some(node)) if node.level() + 1 == level => (),
+            Some(Some(_)) => return Err(format!("node {child} is not one level below {level}")),
+            Some(None) => return Err(format!("node {child} has not been defined yet")),
+        }
+    }
+    Ok(MacroCellNode::Inner(level, [nw, ne, sw, se]))
+}
+
+fn paint_macrocell_node(nodes: &[MacroCellNode], node_idx: usize, x: usize, y: usize, grid: &mut [Vec<bool>]) {
+    // Index 0 is the empty node at every level.
+    let Some(node) = node_idx.checked_sub(1).map(|idx| &nodes[idx]) else { return };
+    match node {
+        MacroCellNode::Leaf(cells) => for &(dx, dy) in cells {
+            grid[x + dx][y + dy] = true;
+        },
+        MacroCellNode::Inner(level, children) => {
+            let half = 1 << (level - 1);
+            for (child_idx, &child) in children.iter().enumerate() {
+                paint_macrocell_node(nodes, child, x + half * (child_idx % 2), y + half * (child_idx / 2), grid);
+            }
+        },
+    }
+}
+
+fn parse_rle_header(header: &str) -> Result<(usize, usize), LifeBoardError> {
+    let invalid = |reason: &str| LifeBoardError::InvalidBoard(format!("Invalid RLE header \"{header}\": {reason}."));
+    let (mut width, mut height) = (None, None);
+    for field in header.split(',') {
+        let (key, value) = field.split_once('=').ok_or_else(|| invalid("expected key = value pairs"))?;
+        let value = value.trim();
+        match key.trim() {
+            "x" => width = Some(value.parse::<usize>().map_err(|_| invalid("x must be a non-negative integer"))?),
+            "y" => height = Some(value.parse::<usize>().map_err(|_| invalid("y must be a non-negative integer"))?),
+            "rule" => if !value.eq_ignore_ascii_case(RLE_RULE) && value != "23/3" {
+                return Err(LifeBoardError::UnsupportedRule(format!("RLE rule \"{value}\" is not B3/S23.")));
+            },
+            _ => return Err(invalid("unknown key")),
+        }
+    }
+    match (width, height) {
+        (Some(width), Some(height)) => Ok((width, height)),
+        _ => Err(invalid("x and y are required")),
+    }
+}
+
+fn apgcode_digit(digit: char) -> Option<u32> {
+    digit.to_digit(36)
+}
+
+// Decodes the extended Wechsler format: 'z' separates 5-row strips, each character of a strip is a
+// column bitmask (low bit on top) and 'w', 'x' and 'y?' are runs of empty columns.
+fn parse_apgcode_cells(code: &str) -> Result<Vec<(usize, usize)>, String> {
+    let mut cells = Vec::new();
+    for (strip_idx, strip) in code.split('z').enumerate() {
+        let mut x = 0;
+        let mut chars = strip.chars();
+        while let Some(tag) = chars.next() {
+            match tag {
+                'w' => x += 2,
+                'x' => x += 3,
+                'y' => match chars.next().and_then(apgcode_digit) {
+                    Some(run) => x += 4 + run as usize,
+                    None => return Err(String::from("'y' must be followed by a run length in 0-9a-z")),
+                },
+                '0'..='9' | 'a'..='v' => {
+                    let column = apgcode_digit(tag).expect("Should always be a base-32 digit");
+                    for bit in (0..5).filter(|bit| column & (1 << bit) != 0) {
+                        cells.push((x, strip_idx * 5 + bit));
+                    }
+                    x += 1;
+                },
+                tag => return Err(format!("unexpected character '{tag}'")),
+            }
+        }
+    }
+    Ok(cells)
+}
+
+impl <T: LifeCell> BaseLifeBoard<T> {
+    pub fn from_apgcode(apgcode: &str, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        let invalid = |reason: &str| LifeBoardError::InvalidBoard(format!("Invalid apgcode \"{apgcode}\": {reason}."));
+        let (prefix, code) = apgcode.split_once('_').ok_or_else(|| invalid("expected a prefix and a code separated by '_'"))?;
+        let (kind, number) = match prefix.get(..2) {
+            Some(kind @ ("xs" | "xp" | "xq")) => (kind, &prefix[2..]),
+            _ => return Err(invalid(&format!("unsupported prefix \"{prefix}\", expected xs, xp or xq"))),
+        };
+        let number = number.parse::<usize>().ok().filter(|&number| number > 0)
+            .ok_or_else(|| invalid(&format!("\"{kind}\" must be followed by a positive integer")))?;
+        let cells = parse_apgcode_cells(code).map_err(|reason| invalid(&reason))?;
+        if kind == "xs" && cells.len() != number {
+            return Err(invalid(&format!("expected a population of {number} but decoded {}", cells.len())));
+        }
+        let x_min = cells.iter().map(|&(x, _)| x).min().ok_or_else(|| invalid("the pattern has no live cells"))?;
+        let y_min = cells.iter().map(|&(_, y)| y).min().expect("Should have at least one cell");
+        let width = cells.iter().map(|&(x, _)| x - x_min + 1).max().expect("Should have at least one cell");
+        let height = cells.iter().map(|&(_, y)| y - y_min + 1).max().expect("Should have at least one cell");
+        check_decoded_size(width, height).map_err(|reason| invalid(&format!("the pattern is too large: {reason}")))?;
+        let mut grid = vec![vec![false; height]; width];
+        for (x, y) in cells {
+            grid[x - x_min][y - y_min] = true;
+        }
+        BaseLifeBoard::from_bool_matrix(grid, init)
+    }
+
+    // Decodes a Golly MacroCell quadtree into the full square it describes, refusing anything with more than `max_cells` cells.
+    pub fn from_macrocell(macrocell: &str, max_cells: usize, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        let invalid_line = |line_idx: usize, reason: &str| LifeBoardError::InvalidBoard(
+            format!("Invalid MacroCell on line {}: {reason}.", line_idx + 1)
+        );
+        let mut lines = macrocell.lines().enumerate().map(|(line_idx, line)| (line_idx, line.trim())).filter(|(_, line)| !line.is_empty());
+        match lines.next() {
+            Some((_, header)) if header.starts_with("[M2]") => (),
+            _ => return Err(LifeBoardError::InvalidBoard(String::from("MacroCell pattern must start with a \"[M2]\" header."))),
+        }
+        let mut nodes = Vec::new();
+        for (line_idx, line) in lines {
+            if let Some(rule) = line.strip_prefix("#R") {
+                if !rule.trim().eq_ignore_ascii_case(RLE_RULE) && rule.trim() != "23/3" {
+                    return Err(LifeBoardError::UnsupportedRule(
+                        format!("MacroCell rule \"{}\" on line {} is not B3/S23.", rule.trim(), line_idx + 1)
+                    ));
+                }
+            } else if line.starts_with('#') {
+                continue;
+            } else if line.starts_with(['.', '*', '$']) {
+                nodes.push(MacroCellNode::Leaf(parse_macrocell_leaf(line).map_err(|reason| invalid_line(line_idx, &reason))?));
+            } else {
+                nodes.push(parse_macrocell_inner(line, &nodes).map_err(|reason| invalid_line(line_idx, &reason))?);
+            }
+        }
+        let root = nodes.last().ok_or_else(|| LifeBoardError::InvalidBoard(String::from("MacroCell pattern has no nodes.")))?;
+        let size = 1usize << root.level();
+        if size.checked_mul(size).is_none_or(|n_cells| n_cells > max_cells) {
+            return Err(LifeBoardError::InvalidBoard(format!(
+                "MacroCell pattern is {size}x{size} cells, which exceeds the limit of {max_cells} cells."
+            )));
+        }
+        let mut grid = vec![vec![false; size]; size];
+        paint_macrocell_node(&nodes, nodes.len(), 0, 0, &mut grid);
+        BaseLifeBoard::from_bool_matrix(grid, init)
+    }
+
+    pub fn from_rle(rle: &str, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        let mut lines = rle.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#'));
+        let header = lines.next().ok_or_else(||
+            LifeBoardError::InvalidBoard(String::from("RLE is missing its header line."))
+        )?;
+        let (width, height) = parse_rle_header(header)?;
+        check_decoded_size(width, height).map_err(|reason| LifeBoardError::InvalidBoard(format!("RLE pattern is too large: {reason}.")))?;
+        let out_of_bounds = |x: usize, y: usize| LifeBoardError::InvalidBoard(format!(
+            "RLE pattern exceeds its declared {width}x{height} bounds at ({x}, {y})."
+        ));
+        let mut grid = vec![vec![false; height]; width];
+        let (mut x, mut y) = (0usize, 0usize);
+        let mut count: Option<usize> = None;
+        let mut terminated = false;
+        'lines: for line in lines {
+            for tag in line.chars() {
+                let run = count.unwrap_or(1);
+                match tag {
+                    '0'..='9' => {
+                        let digit = tag.to_digit(10).expect("Should always be a decimal digit") as usize;
+                        count = Some(count.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+                        continue;
+                    },
+                    '!' => {
+                        terminated = true;
+                        break 'lines;
+                    },
+                    '$' => {
+                        y = y.checked_add(run).ok_or_else(|| out_of_bounds(x, y))?;
+                        x = 0;
+                    },
+                    'b' | '.' => x = x.checked_add(run).ok_or_else(|| out_of_bounds(x, y))?,
+                    tag if tag.is_ascii_alphabetic() => {
+                        let end = x.checked_add(run).filter(|&end| y < height && end <= width).ok_or_else(|| out_of_bounds(x, y))?;
+                        for col in &mut grid[x..end] {
+                            col[y] = true;
+                        }
+                        x = end;
+                    },
+                    tag if tag.is_whitespace() => (),
+                    tag => return Err(LifeBoardError::InvalidBoard(format!("Unexpected RLE character '{tag}'."))),
+                }
+                count = None;
+            }
+        }
+        if !terminated {
+            return Err(LifeBoardError::InvalidBoard(String::from("RLE pattern must be terminated by '!'.")));
+        }
+        BaseLifeBoard::from_bool_matrix(grid, init)
+    }
+
+    pub fn to_ascii(&self, alive: char, dead: char) -> String {
+        let mut ascii = String::with_capacity((self.width() + 1) * self.height());
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let cell_alive = self.is_cell_alive(x, y).expect("Should always be valid indices");
+                ascii.push(if cell_alive { alive } else { dead });
+            }
+            ascii.push('\n');
+        }
+        ascii
+    }
+
+    pub fn from_ascii(ascii: &str, alive: char, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        let rows: Vec<Vec<bool>> = ascii.lines().map(|line| line.chars().map(|cell| cell == alive).collect()).collect();
+        let width = rows.first().map_or(0, |row| row.len());
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(LifeBoardError::InvalidBoard(format!(
+                "ASCII board must have rows of consistent length: row {y} has {} cells but expected {width}.",
+                rows[y].len()
+            )));
+        }
+        let grid = (0..width).map(|x| rows.iter().map(|row| row[x]).collect::<Vec<bool>>()).collect::<Vec<_>>();
+        BaseLifeBoard::from_bool_matrix(grid, init)
+    }
+
+    pub fn from_csv(csv: &str, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        let rows = csv.lines().map(str::trim).filter(|line| !line.is_empty()).enumerate().map(|(y, line)|
+            line.split(',').enumerate().map(|(x, value)| match value.trim() {
+                "0" => Ok(false),
+                "1" => Ok(true),
+                value => Err(LifeBoardError::InvalidBoard(format!(
+                    "Invalid CSV value \"{value}\" at row {y}, column {x}: expected 0 or 1."
+                ))),
+            }).collect::<Result<Vec<bool>, _>>()
+        ).collect::<Result<Vec<_>, _>>()?;
+        let width = rows.first().map_or(0, |row| row.len());
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(LifeBoardError::InvalidBoard(format!(
+                "CSV board must have rows of consistent length: row {y} has {} cells but expected {width}.",
+                rows[y].len()
+            )));
+        }
+        let grid = (0..width).map(|x| rows.iter().map(|row| row[x]).collect::<Vec<bool>>()).collect::<Vec<_>>();
+        BaseLifeBoard::from_bool_matrix(grid, init)
+    }
+
+    pub fn to_plaintext(&self) -> String { self.to_ascii(PLAINTEXT_ALIVE, PLAINTEXT_DEAD) }
+
+    // Reads the `.cells` format: lines starting with '!' are comments and rows may omit their trailing dead cells.
+    pub fn from_plaintext(plaintext: &str, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        let rows: Vec<&str> = plaintext.lines().map(str::trim_end).filter(|line| !line.starts_with('!')).collect();
+        let width = rows.iter().map(|row| row.chars().count()).max().unwrap_or(0);
+        let padded = rows.iter()
+            .map(|row| format!("{row}{}", PLAINTEXT_DEAD.to_string().repeat(width - row.chars().count())))
+            .collect::<Vec<_>>()
+            .join("\n");
+        BaseLifeBoard::from_ascii(&padded, PLAINTEXT_ALIVE, init)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let row_bytes = self.width().div_ceil(8);
+        let mut bytes = Vec::with_capacity(SNAPSHOT_HEADER_LEN + row_bytes * self.height());
+        bytes.extend_from_slice(SNAPSHOT_MAGIC);
+        bytes.push(SNAPSHOT_VERSION);
+        bytes.extend_from_slice(&(self.width() as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.height() as u64).to_le_bytes());
+        for y in 0..self.height() {
+            let mut row = vec![0u8; row_bytes];
+            for x in 0..self.width() {
+                if self.is_cell_alive(x, y) == Ok(true) {
+                    row[x / 8] |= 1 << (x % 8);
+                }
+            }
+            bytes.extend(row);
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8], init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        let invalid = |reason: &str| LifeBoardError::InvalidBoard(format!("Invalid board snapshot: {reason}."));
+        if bytes.len() < SNAPSHOT_HEADER_LEN {
+            return Err(invalid("truncated header"));
+        }
+        if &bytes[0..4] != SNAPSHOT_MAGIC {
+            return Err(invalid("unrecognized magic bytes"));
+        }
+        if bytes[4] != SNAPSHOT_VERSION {
+            return Err(invalid(&format!("unsupported version {}", bytes[4])));
+        }
+        let read_dimension = |offset: usize| -> Result<usize, LifeBoardError> {
+            let value = u64::from_le_bytes(bytes[offset..offset + 8].try_into().expect("Should always be 8 bytes"));
+            usize::try_from(value).map_err(|_| invalid("dimensions are too large"))
+        };
+        let (width, height) = (read_dimension(5)?, read_dimension(13)?);
+        if width == 0 || height == 0 {
+            return Err(invalid("dimensions must be non-zero"));
+        }
+        let row_bytes = width.div_ceil(8);
+        let expected_len = row_bytes.checked_mul(height)
+            .and_then(|len| len.checked_add(SNAPSHOT_HEADER_LEN))
+            .ok_or_else(|| invalid("dimensions are too large"))?;
+        if bytes.len() < expected_len {
+            return Err(invalid("truncated cell data"));
+        }
+        if bytes.len() > expected_len {
+            return Err(invalid("unexpected trailing data"));
+        }
+        check_decoded_size(width, height).map_err(|reason| invalid(&reason))?;
+        let rows: Vec<&[u8]> = bytes[SNAPSHOT_HEADER_LEN..].chunks(row_bytes).collect();
+        if width % 8 != 0 && rows.iter().any(|row| row[row_bytes - 1] >> (width % 8) != 0) {
+            return Err(invalid("padding bits must be zero"));
+        }
+        let grid = (0..width).map(|x|
+            rows.iter().map(|row| row[x / 8] & (1 << (x % 8)) != 0).collect::<Vec<bool>>()
+        ).collect::<Vec<_>>();
+        BaseLifeBoard::from_bool_matrix(grid, init)
+    }
+
+    pub fn from_life106(life106: &str, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        let cells = parse_life106(life106)?;
+        let (min_x, max_x) = match (cells.iter().map(|&(x, _)| x).min(), cells.iter().map(|&(x, _)| x).max()) {
+            (Some(min_x), Some(max_x)) => (min_x, max_x),
+            _ => return Err(LifeBoardError::InvalidBoard(String::from("Life 1.06 pattern has no live cells."))),
+        };
+        let min_y = cells.iter().map(|&(_, y)| y).min().expect("Should have at least one cell");
+        let max_y = cells.iter().map(|&(_, y)| y).max().expect("Should have at least one cell");
+        let span = |min: i64, max: i64| max.checked_sub(min).and_then(|span| usize::try_from(span).ok()?.checked_add(1));
+        let (width, height) = match (span(min_x, max_x), span(min_y, max_y)) {
+            (Some(width), Some(height)) => (width, height),
+            _ => return Err(LifeBoardError::InvalidBoard(String::from("Life 1.06 pattern is too large: its cells are too far apart."))),
+        };
+        check_decoded_size(width, height).map_err(|reason| LifeBoardError::InvalidBoard(format!("Life 1.06 pattern is too large: {reason}.")))?;
+        let mut grid = vec![vec![false; height]; width];
+        for (x, y) in cells {
+            grid[x.abs_diff(min_x) as usize][y.abs_diff(min_y) as usize] = true;
+        }
+        BaseLifeBoard::from_bool_matrix(grid, init)
+    }
+
+    pub fn from_life106_with_size(life106: &str, width: usize, height: usize, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        let mut grid = vec![vec![false; height]; width];
+        for (x, y) in parse_life106(life106)? {
+            match (usize::try_from(x), usize::try_from(y)) {
+                (Ok(x), Ok(y)) if x < width && y < height => grid[x][y] = true,
+                _ => return Err(LifeBoardError::InvalidBoard(format!(
+                    "Life 1.06 cell ({x}, {y}) is outside of the {width}x{height} board."
+                ))),
+            }
+        }
+        BaseLifeBoard::from_bool_matrix(grid, init)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life_implementation::{BaseLifeBoard, Cell, ParallelLifeBoard};
+    use crate::patterns;
+    use crate::life_interface::{LifeBoard, LifeBoardError};
+    use crate::test_fixtures::get_7x7_start_board_0th_gen;
+
+    fn assert_rle_round_trip(board: BaseLifeBoard<Cell>) {
+        let rle = board.to_rle();
+        let parsed = BaseLifeBoard::from_rle(&rle, Cell::from_bool).unwrap();
+        assert_eq!(board, parsed, "\nRLE:\n{rle}");
+    }
+
+    #[test]
+    fn test_equivalence_rle_round_trip_7x7_board() {
+        let mut board = get_7x7_start_board_0th_gen();
+        for _ in 0..10 {
+            assert_rle_round_trip(board.clone());
+            board.simulate();
+        }
+    }
+
+    #[test]
+    fn test_equivalence_rle_round_trip_random_100x100_board() {
+        assert_rle_round_trip(BaseLifeBoard::gen(100, 100, Cell::gen));
+    }
+
+    #[test]
+    fn test_equivalence_rle_glider() {
+        let glider = BaseLifeBoard::from_bool_matrix([
+            [false, false, true],
+            [true, false, true],
+            [false, true, true],
+        ], Cell::from_bool).unwrap();
+        assert_eq!("x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!\n", glider.to_rle());
+        assert_eq!(glider.to_rle(), ParallelLifeBoard::from_board(glider, 2).to_rle());
+    }
+
+    #[test]
+    fn test_equivalence_rle_omits_trailing_dead_cells_and_rows() {
+        let board = BaseLifeBoard::from_bool_matrix([
+            [true, false, false, false],
+            [false, false, false, false],
+            [false, false, true, false],
+            [false, false, false, false],
+        ], Cell::from_bool).unwrap();
+        assert_eq!("x = 4, y = 4, rule = B3/S23\no2$2bo!\n", board.to_rle());
+    }
+
+    #[test]
+    fn test_boundary_rle_wraps_lines_at_70_characters() {
+        let grid: Vec<Vec<bool>> = (0..200).map(|x| vec![x % 2 == 0; 2]).collect();
+        let board = BaseLifeBoard::from_bool_matrix(grid, Cell::from_bool).unwrap();
+        let rle = board.to_rle();
+        for line in rle.lines() {
+            assert!(line.len() <= 70, "Line \"{line}\" exceeds 70 characters");
+        }
+        assert_rle_round_trip(board);
+    }
+
+    const LIFE106_GLIDER: &str = "#Life 1.06\n0 -1\n1 0\n-1 1\n0 1\n1 1\n";
+
+    fn board_from_cells(width: usize, height: usize, cells: &[(usize, usize)]) -> BaseLifeBoard<Cell> {
+        let mut grid = vec![vec![false; height]; width];
+        for &(x, y) in cells {
+            grid[x][y] = true;
+        }
+        BaseLifeBoard::from_bool_matrix(grid, Cell::from_bool).unwrap()
+    }
+
+    #[test]
+    fn test_equivalence_life106_import_glider() {
+        let glider = BaseLifeBoard::from_life106(LIFE106_GLIDER, Cell::from_bool).unwrap();
+        assert_eq!(board_from_cells(3, 3, &[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]), glider);
+    }
+
+    #[test]
+    fn test_equivalence_life106_glider_moves() {
+        let mut glider = BaseLifeBoard::from_life106(LIFE106_GLIDER, Cell::from_bool).unwrap();
+        glider.resize(8, 8, false).unwrap();
+        glider.simulate_n_steps(4);
+        assert_eq!(board_from_cells(8, 8, &[(2, 1), (3, 2), (1, 3), (2, 3), (3, 3)]), glider);
+    }
+
+    #[test]
+    fn test_equivalence_life106_round_trip() {
+        let glider = BaseLifeBoard::from_life106(LIFE106_GLIDER, Cell::from_bool).unwrap();
+        let life106 = glider.to_life106();
+        assert_eq!("#Life 1.06\n1 0\n2 1\n0 2\n1 2\n2 2\n", life106);
+        assert_eq!(glider, BaseLifeBoard::from_life106(&life106, Cell::from_bool).unwrap());
+
+        let board = get_7x7_start_board_0th_gen();
+        assert_eq!(board, BaseLifeBoard::from_life106(&board.to_life106(), Cell::from_bool).unwrap());
+    }
+
+    #[test]
+    fn test_equivalence_life106_with_size_round_trip() {
+        let board = board_from_cells(6, 5, &[(2, 1), (3, 2), (1, 3), (2, 3), (3, 3)]);
+        let life106 = board.to_life106();
+        assert_eq!("#Life 1.06\n2 1\n3 2\n1 3\n2 3\n3 3\n", life106);
+        assert_eq!(board, BaseLifeBoard::from_life106_with_size(&life106, 6, 5, Cell::from_bool).unwrap());
+        let empty_board = BaseLifeBoard::from_life106_with_size("#Life 1.06\n", 2, 3, Cell::from_bool).unwrap();
+        assert_eq!(board_from_cells(2, 3, &[]), empty_board);
+    }
+
+    #[test]
+    fn test_exception_life106_with_size_cell_out_of_bounds() {
+        for (life106, cell) in [("#Life 1.06\n0 0\n3 1\n", "(3, 1)"), ("#Life 1.06\n-1 0\n", "(-1, 0)")] {
+            match BaseLifeBoard::from_life106_with_size(life106, 3, 3, Cell::from_bool) {
+                Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains(cell), "{error}"),
+                result => panic!("Expected an InvalidBoard error but found {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_exception_life106_malformed_line() {
+        match BaseLifeBoard::from_life106("#Life 1.06\n0 0\n1 x\n", Cell::from_bool) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("line 3"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+        match BaseLifeBoard::from_life106("#Life 1.06\n0 0 0\n", Cell::from_bool) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("line 2"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_exception_life106_exceeds_size_limit() {
+        for life106 in ["#Life 1.06\n-9223372036854775808 0\n9223372036854775807 0\n", "#Life 1.06\n0 0\n100000 100000\n"] {
+            match BaseLifeBoard::from_life106(life106, Cell::from_bool) {
+                Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("too large"), "{error}"),
+                result => panic!("Expected an InvalidBoard error but found {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_exception_life106_missing_header() {
+        match BaseLifeBoard::from_life106("0 0\n", Cell::from_bool) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("header"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_equivalence_apgcode_matches_pattern_library() {
+        assert_eq!(patterns::glider(Cell::from_bool), BaseLifeBoard::from_apgcode("xq4_153", Cell::from_bool).unwrap());
+        assert_eq!(patterns::block(Cell::from_bool), BaseLifeBoard::from_apgcode("xs4_33", Cell::from_bool).unwrap());
+        assert_eq!(patterns::blinker(Cell::from_bool), BaseLifeBoard::from_apgcode("xp2_7", Cell::from_bool).unwrap());
+        assert_eq!(patterns::beehive(Cell::from_bool), BaseLifeBoard::from_apgcode("xs6_696", Cell::from_bool).unwrap());
+        assert_eq!(patterns::toad(Cell::from_bool), BaseLifeBoard::from_apgcode("xp2_7e", Cell::from_bool).unwrap());
+    }
+
+    #[test]
+    fn test_equivalence_apgcode_multiple_strips_and_gaps() {
+        let board = BaseLifeBoard::from_apgcode("xs3_1w1z1", Cell::from_bool).unwrap();
+        assert_eq!(board_from_cells(4, 6, &[(0, 0), (3, 0), (0, 5)]), board);
+        let board = BaseLifeBoard::from_apgcode("xs2_1y01", Cell::from_bool).unwrap();
+        assert_eq!(board_from_cells(6, 1, &[(0, 0), (5, 0)]), board);
+    }
+
+    #[test]
+    fn test_exception_apgcode_invalid_codes() {
+        for (apgcode, reason) in [
+            ("yl144_1_16_afb5f3db909e60548f086e22ee3353ac", "unsupported prefix"),
+            ("xé_1", "unsupported prefix"),
+            ("x_1", "unsupported prefix"),
+            ("xs4", "separated by '_'"),
+            ("xs_33", "positive integer"),
+            ("xq4_15!", "unexpected character '!'"),
+            ("xp2_y", "run length"),
+            ("xs5_33", "population of 5"),
+            ("xp2_0", "no live cells"),
+        ] {
+            match BaseLifeBoard::from_apgcode(apgcode, Cell::from_bool) {
+                Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains(reason), "{error}"),
+                result => panic!("Expected an InvalidBoard error for {apgcode} but found {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_exception_apgcode_too_large() {
+        let apgcode = format!("xp2_{}1{}1", "yy".repeat(1000), "z".repeat(1000));
+        match BaseLifeBoard::from_apgcode(&apgcode, Cell::from_bool) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("38001x5001 cells exceeds"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+        let apgcode = format!("xs2_{}1{}1", "z".repeat(1000), "yy".repeat(1000));
+        assert_eq!(board_from_cells(38002, 1, &[(0, 0), (38001, 0)]), BaseLifeBoard::from_apgcode(&apgcode, Cell::from_bool).unwrap());
+    }
+
+    const MACROCELL_GLIDER: &str = "[M2] (golly 4.2)\n#R B3/S23\n#C A glider in the south-east quadrant\n.*$..*$***$\n4 0 0 0 1\n";
+
+    #[test]
+    fn test_equivalence_macrocell_glider_matches_rle() {
+        let board = BaseLifeBoard::from_macrocell(MACROCELL_GLIDER, 256, Cell::from_bool).unwrap();
+        assert_eq!((16, 16), (board.width(), board.height()));
+        assert_eq!(Ok(true), board.is_cell_alive(9, 8));
+        let rle_glider = BaseLifeBoard::from_rle("x = 3, y = 3\nbo$2bo$3o!", Cell::from_bool).unwrap();
+        assert_eq!(rle_glider, board.trimmed().unwrap());
+    }
+
+    #[test]
+    fn test_equivalence_macrocell_shared_nodes() {
+        let board = BaseLifeBoard::from_macrocell("[M2]\n**$**$\n4 1 0 0 1\n5 2 2 0 2\n", 1024, Cell::from_bool).unwrap();
+        assert_eq!((32, 32, 24), (board.width(), board.height(), board.count_alive()));
+        assert_eq!(Ok(true), board.is_cell_alive(24, 24));
+        assert_eq!(Ok(false), board.is_cell_alive(0, 16));
+    }
+
+    #[test]
+    fn test_exception_macrocell_exceeds_size_limit() {
+        match BaseLifeBoard::from_macrocell(MACROCELL_GLIDER, 255, Cell::from_bool) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("limit of 255"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_exception_macrocell_invalid_lines() {
+        for (macrocell, reason) in [
+            ("[M2]\n.*$\n4 0 0 0 2\n", "line 3"),
+            ("[M2]\n.*$\n5 1 0 0 0\n", "line 3"),
+            ("[M2]\n.*x$\n", "line 2"),
+            ("[M2]\n.........*$\n", "line 2"),
+            ("[M2]\n.*$\n\n4 1 0 zero 0\n", "line 4"),
+            ("golly\n.*$\n", "header"),
+            ("[M2]\n#C nothing here\n", "no nodes"),
+        ] {
+            match BaseLifeBoard::from_macrocell(macrocell, 1 << 20, Cell::from_bool) {
+                Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains(reason), "{error}"),
+                result => panic!("Expected an InvalidBoard error for {macrocell:?} but found {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_exception_unsupported_rule() {
+        for result in [
+            BaseLifeBoard::from_rle("x = 3, y = 3, rule = B36/S23\nbo$2bo$3o!", Cell::from_bool),
+            BaseLifeBoard::from_macrocell("[M2]\n#R B36/S23\n.*$\n", 1 << 20, Cell::from_bool),
+        ] {
+            match result {
+                Err(LifeBoardError::UnsupportedRule(error)) => assert!(error.contains("B36/S23"), "{error}"),
+                result => panic!("Expected an UnsupportedRule error but found {result:?}"),
+            }
+        }
+    }
+
+    const ASCII_GLIDER: &str = ".O...\n..O..\nOOO..\n";
+
+    #[test]
+    fn test_equivalence_ascii_round_trip() {
+        let board = BaseLifeBoard::from_ascii(ASCII_GLIDER, 'O', Cell::from_bool).unwrap();
+        assert_eq!((5, 3), (board.width(), board.height()));
+        assert_eq!(Ok(true), board.is_cell_alive(1, 0));
+        assert_eq!(Ok(false), board.is_cell_alive(0, 0));
+        assert_eq!(ASCII_GLIDER, board.to_ascii('O', '.'));
+
+        let board = get_7x7_start_board_0th_gen();
+        let ascii = board.to_ascii('#', ' ');
+        assert_eq!(board, BaseLifeBoard::from_ascii(&ascii, '#', Cell::from_bool).unwrap());
+    }
+
+    #[test]
+    fn test_equivalence_ascii_unknown_characters_are_dead() {
+        let board = BaseLifeBoard::from_ascii("x?\r\n x\r\n", 'x', Cell::from_bool).unwrap();
+        assert_eq!("*.\n.*\n", board.to_ascii('*', '.'));
+    }
+
+    #[test]
+    fn test_exception_ascii_ragged_rows() {
+        match BaseLifeBoard::from_ascii("OO\nO\n", 'O', Cell::from_bool) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("row 1"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+        match BaseLifeBoard::from_ascii("", 'O', Cell::from_bool) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("at least one cell wide"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_equivalence_plaintext_ragged_rows_and_comments() {
+        let board = BaseLifeBoard::from_plaintext("!Name: Glider\n.O\n..O\nOOO\n", Cell::from_bool).unwrap();
+        assert_eq!(BaseLifeBoard::from_rle("x = 3, y = 3\nbo$2bo$3o!", Cell::from_bool).unwrap(), board);
+        assert_eq!(".O.\n..O\nOOO\n", board.to_plaintext());
+        assert_eq!(board, BaseLifeBoard::from_plaintext(&board.to_plaintext(), Cell::from_bool).unwrap());
+    }
+
+    // Checks that every opened tag is closed in order, which is as much XML validation as the tests need.
+    fn assert_balanced_tags(svg: &str) {
+        let mut open_tags = Vec::new();
+        for tag in svg.split('<').skip(1).map(|rest| rest.split('>').next().unwrap()) {
+            let name = tag.trim_start_matches('/').split_whitespace().next().unwrap().trim_end_matches('/');
+            if let Some(closing) = tag.strip_prefix('/') {
+                assert_eq!(open_tags.pop(), Some(closing.trim()), "Unbalanced closing tag in {svg}");
+            } else if !tag.ends_with('/') {
+                open_tags.push(name);
+            }
+        }
+        assert!(open_tags.is_empty(), "Unclosed tags {open_tags:?} in {svg}");
+    }
+
+    #[test]
+    fn test_equivalence_svg_glider_rects() {
+        let svg = patterns::glider(Cell::from_bool).to_svg(10.0, "#000", Some("white"), Some("#ccc"));
+        assert_balanced_tags(&svg);
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"30\""), "{svg}");
+        // One background rect plus one rect per run: the top row's run of three and the two single cells.
+        assert_eq!(4, svg.matches("<rect").count(), "{svg}");
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"30\" height=\"10\"/>"), "{svg}");
+        assert!(svg.contains("stroke=\"#ccc\""), "{svg}");
+    }
+
+    #[test]
+    fn test_equivalence_svg_merges_full_row() {
+        let board = BaseLifeBoard::from_rle("x = 6, y = 2\n6o!", Cell::from_bool).unwrap();
+        let svg = board.to_svg(2.5, "red\"&", None, None);
+        assert_balanced_tags(&svg);
+        assert_eq!(1, svg.matches("<rect").count(), "{svg}");
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"15\" height=\"2.5\"/>"), "{svg}");
+        assert!(svg.contains("fill=\"red&quot;&amp;\""), "{svg}");
+    }
+
+    #[test]
+    fn test_equivalence_csv_round_trip() {
+        let board = get_7x7_start_board_0th_gen();
+        let csv = board.to_csv();
+        assert_eq!("0,0,0,0,1,1,0\n1,1,0,0,0,0,0\n", &csv[..28]);
+        assert_eq!(board, BaseLifeBoard::from_csv(&csv, Cell::from_bool).unwrap());
+    }
+
+    #[test]
+    fn test_equivalence_csv_round_trip_wide_board() {
+        let board = BaseLifeBoard::gen(40, 3, Cell::gen);
+        assert_eq!(board, BaseLifeBoard::from_csv(&board.to_csv(), Cell::from_bool).unwrap());
+    }
+
+    #[test]
+    fn test_equivalence_csv_with_header() {
+        let board = BaseLifeBoard::from_bool_matrix([[true, false], [false, false], [false, true]], Cell::from_bool).unwrap();
+        assert_eq!("0,1,2\n1,0,0\n0,0,1\n", board.to_csv_with_header());
+    }
+
+    #[test]
+    fn test_exception_csv_invalid_input() {
+        for (csv, expected) in [("0,1\n0,2\n", "\"2\" at row 1, column 1"), ("0,1\n0\n", "row 1 has 1 cells but expected 2")] {
+            match BaseLifeBoard::from_csv(csv, Cell::from_bool) {
+                Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains(expected), "{error}"),
+                result => panic!("Expected InvalidBoard for {csv:?} but found {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_equivalence_bytes_round_trip_awkward_widths() {
+        for width in [1, 7, 8, 9, 63, 64, 65] {
+            for height in [1, 5] {
+                let board = BaseLifeBoard::gen(width, height, Cell::gen);
+                let bytes = board.to_bytes();
+                assert_eq!(21 + width.div_ceil(8) * height, bytes.len());
+                assert_eq!(board, BaseLifeBoard::from_bytes(&bytes, Cell::from_bool).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_exception_bytes_truncated() {
+        let bytes = BaseLifeBoard::gen(9, 3, Cell::gen).to_bytes();
+        for len in 0..bytes.len() {
+            match BaseLifeBoard::from_bytes(&bytes[..len], Cell::from_bool) {
+                Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("truncated"), "{error}"),
+                result => panic!("Expected an InvalidBoard error for {len} bytes but found {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_exception_bytes_corrupted() {
+        let bytes = BaseLifeBoard::gen(9, 3, Cell::gen).to_bytes();
+        let corruptions: [(usize, u8, &str); 4] = [
+            (0, b'X', "magic"),
+            (4, 2, "version"),
+            (12, 0xff, "truncated"),
+            (22, 0xfe, "padding"),
+        ];
+        for (offset, value, reason) in corruptions {
+            let mut corrupted = bytes.clone();
+            corrupted[offset] = value;
+            match BaseLifeBoard::from_bytes(&corrupted, Cell::from_bool) {
+                Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains(reason), "{error}"),
+                result => panic!("Expected an InvalidBoard error but found {result:?}"),
+            }
+        }
+        let mut extended = bytes.clone();
+        extended.push(0);
+        match BaseLifeBoard::from_bytes(&extended, Cell::from_bool) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("trailing"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_exception_bytes_zero_or_oversized_dimensions() {
+        let header = |width: u64, height: u64| [&b"LIFE\x01"[..], &width.to_le_bytes(), &height.to_le_bytes()].concat();
+        let cases = [
+            (header(1 << 60, 0), "non-zero"),
+            (header(0, 1 << 60), "non-zero"),
+            ([header(1 << 16, 1 << 12), vec![0; (1 << 13) << 12]].concat(), "limit"),
+        ];
+        for (bytes, reason) in cases {
+            match BaseLifeBoard::from_bytes(&bytes, Cell::from_bool) {
+                Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains(reason), "{error}"),
+                result => panic!("Expected an InvalidBoard error but found {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_exception_rle_pattern_out_of_bounds() {
+        match BaseLifeBoard::from_rle("x = 2, y = 2\n3o!", Cell::from_bool) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("bounds"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_exception_rle_run_overflow() {
+        for rle in ["x = 3, y = 3\nb99999999999999999999o!", "x = 3, y = 3\n99999999999999999999$o$99999999999999999999$o!"] {
+            match BaseLifeBoard::from_rle(rle, Cell::from_bool) {
+                Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("bounds"), "{error}"),
+                result => panic!("Expected an InvalidBoard error but found {result:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_exception_rle_header_exceeds_size_limit() {
+        match BaseLifeBoard::from_rle("x = 100000, y = 100000\no!", Cell::from_bool) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("limit"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_exception_rle_missing_terminator() {
+        match BaseLifeBoard::from_rle("x = 3, y = 1\n3o", Cell::from_bool) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("'!'"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+    }
+}
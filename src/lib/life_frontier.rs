@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use crate::life_implementation::BaseLifeBoard;
+use crate::life_interface;
+use crate::life_interface::{LifeBoard, LifeBoardError, LifeCell, StepStats};
+
+// A cell can only change if something in its neighborhood changed last step, so `frontier` holds the
+// neighborhoods of every cell that was born or died. Still lifes drop out of the frontier entirely.
+#[derive(Clone)]
+pub struct FrontierLifeBoard<T: LifeCell> {
+    board: BaseLifeBoard<T>,
+    frontier: HashSet<(usize, usize)>,
+    population: usize,
+} impl<T: LifeCell> FrontierLifeBoard<T> {
+    pub fn from_board(board: BaseLifeBoard<T>) -> FrontierLifeBoard<T> {
+        let frontier = FrontierLifeBoard::_live_frontier(&board);
+        let population = board.count_alive();
+        FrontierLifeBoard { board, frontier, population }
+    }
+
+    fn _live_frontier(board: &BaseLifeBoard<T>) -> HashSet<(usize, usize)> {
+        let mut frontier = HashSet::new();
+        for x in 0..board.width() {
+            for y in 0..board.height() {
+                if board.is_cell_alive(x, y) == Ok(true) {
+                    FrontierLifeBoard::_mark_neighborhood(board, &mut frontier, x, y);
+                }
+            }
+        }
+        frontier
+    }
+
+    // Growing the board shifts every cell, so the frontier is rebuilt around the live cells.
+    fn _apply_auto_expand(&mut self) {
+        if self.board._apply_auto_expand() {
+            self.frontier = FrontierLifeBoard::_live_frontier(&self.board);
+        }
+    }
+
+    pub fn into_board(self) -> BaseLifeBoard<T> { self.board }
+
+    pub fn frontier(&self) -> &HashSet<(usize, usize)> { &self.frontier }
+
+    fn _mark_neighborhood(board: &BaseLifeBoard<T>, frontier: &mut HashSet<(usize, usize)>, x: usize, y: usize) {
+        let radius = board.neighborhood().radius();
+        // Neighbors across a wrapping edge can change too, so they join the frontier instead of being clipped.
+        for dx in -radius..=radius {
+            let Some(nx) = life_interface::wrapped_coordinate(x as i64 + dx, board.width(), board.wrap_x()) else { continue };
+            for dy in -radius..=radius {
+                if let Some(ny) = life_interface::wrapped_coordinate(y as i64 + dy, board.height(), board.wrap_y()) {
+                    frontier.insert((nx, ny));
+                }
+            }
+        }
+    }
+} impl<T: LifeCell> LifeBoard<T> for FrontierLifeBoard<T> {
+    fn width(&self) -> usize { self.board.width() }
+
+    fn height(&self) -> usize { self.board.height() }
+
+    fn simulate(&mut self) {
+        self.simulate_stats();
+    }
+
+    fn simulate_stats(&mut self) -> StepStats {
+        self._apply_auto_expand();
+        let mut stats = StepStats::default();
+        let mut changes = Vec::new();
+        for &(x, y) in &self.frontier {
+            let was_alive = self.board.is_cell_alive(x, y) == Ok(true);
+            let new_cell = self.board.next_cell_state_at(x, y).expect("Should always access a valid index");
+            stats.record(was_alive, new_cell.is_alive());
+            if was_alive != new_cell.is_alive() {
+                changes.push((x, y, new_cell));
+            }
+        }
+        let mut frontier = HashSet::new();
+        for (x, y, new_cell) in changes {
+            self.board._set_cell(x, y, new_cell);
+            FrontierLifeBoard::_mark_neighborhood(&self.board, &mut frontier, x, y);
+        }
+        // Survivors outside of the frontier are never visited, so derive them from the population instead.
+        stats.survivals = self.population - stats.deaths;
+        self.population = self.population + stats.births - stats.deaths;
+        self.frontier = frontier;
+        self._apply_auto_expand();
+        stats
+    }
+
+    fn next_cell_state_at(&self, x: usize, y: usize) -> Result<T, LifeBoardError> { self.board.next_cell_state_at(x, y) }
+
+    fn cell_at(&self, x: usize, y: usize) -> Result<&T, LifeBoardError> { self.board.cell_at(x, y) }
+
+    fn num_alive_neighbors_at(&self, x: usize, y: usize) -> Result<u8, LifeBoardError> { self.board.num_alive_neighbors_at(x, y) }
+
+    fn is_cell_alive(&self, x: usize, y: usize) -> Result<bool, LifeBoardError> { self.board.is_cell_alive(x, y) }
+
+    fn to_vec_matrix(&self) -> Vec<Vec<T>> { self.board.to_vec_matrix() }
+
+    // Any filled cell may have changed, so the whole region joins the frontier.
+    fn fill_region(&mut self, x: usize, y: usize, w: usize, h: usize, alive: bool) -> Result<(), LifeBoardError> {
+        self.board.fill_region(x, y, w, h, alive)?;
+        for x in x..x + w {
+            for y in y..y + h {
+                FrontierLifeBoard::_mark_neighborhood(&self.board, &mut self.frontier, x, y);
+            }
+        }
+        self.population = self.board.count_alive();
+        Ok(())
+    }
+
+    fn count_alive(&self) -> usize { self.population }
+
+    fn is_stable(&self) -> bool {
+        self.frontier.iter().all(|&(x, y)|
+            self.board.next_cell_state_at(x, y).map(|cell| cell.is_alive()) == self.board.is_cell_alive(x, y)
+        )
+    }
+} impl<T: LifeCell> PartialEq for FrontierLifeBoard<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.board == other.board
+    }
+} impl<T: LifeCell> Display for FrontierLifeBoard<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.board, f)
+    }
+} impl<T: LifeCell> Debug for FrontierLifeBoard<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.board, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life_frontier::FrontierLifeBoard;
+    use crate::life_implementation::{BaseLifeBoard, Cell, Neighborhood};
+    use crate::life_interface::LifeBoard;
+    use crate::patterns;
+    use crate::test_fixtures;
+
+    fn assert_matches_base_board(base_board: BaseLifeBoard<Cell>, steps: usize) {
+        let frontier_board = FrontierLifeBoard::from_board(base_board.clone());
+        test_fixtures::assert_matches_base_board(base_board, frontier_board, steps, |board| board.clone().into_board());
+    }
+
+    #[test]
+    fn test_equivalence_frontier_lone_glider_on_large_board() {
+        let mut board = patterns::glider(Cell::from_bool);
+        board.resize(200, 150, false).unwrap();
+        board.translate(100, 70, false);
+        assert_matches_base_board(board, 20);
+    }
+
+    #[test]
+    fn test_equivalence_frontier_matches_base_board() {
+        for (width, height) in [(1, 1), (7, 7), (30, 20)] {
+            assert_matches_base_board(BaseLifeBoard::gen(width, height, Cell::gen), 25);
+        }
+        let mut board = BaseLifeBoard::gen(20, 20, Cell::gen);
+        board.set_neighborhood(Neighborhood::MooreRadius(2)).unwrap();
+        assert_matches_base_board(board, 10);
+    }
+
+    #[test]
+    fn test_equivalence_frontier_matches_base_board_50_generations() {
+        for seed in 0..8 {
+            let (width, height) = (16 + seed as usize * 5, 40 - seed as usize * 3);
+            let mut board = BaseLifeBoard::gen_seeded(width, height, 0.35, seed, Cell::from_bool);
+            board.set_wrap(seed % 2 == 1, seed % 4 >= 2);
+            assert_matches_base_board(board, 50);
+        }
+    }
+
+    #[test]
+    fn test_equivalence_frontier_matches_base_board_auto_expand() {
+        let mut board = patterns::glider(Cell::from_bool);
+        board.set_auto_expand(Some(2));
+        assert_matches_base_board(board, 40);
+    }
+
+    #[test]
+    fn test_boundary_frontier_still_life_empties_frontier() {
+        let mut block = patterns::block(Cell::from_bool);
+        block.resize(10, 10, false).unwrap();
+        block.translate(4, 4, false);
+        let mut board = FrontierLifeBoard::from_board(block);
+        assert_eq!(16, board.frontier().len());
+        board.simulate();
+        assert!(board.frontier().is_empty());
+        assert!(board.is_stable());
+        assert_eq!(4, board.count_alive());
+    }
+}
@@ -0,0 +1,445 @@
+use std::collections::HashMap;
+use crate::life_format;
+use crate::life_implementation::BaseLifeBoard;
+use crate::life_interface::{LifeBoard, LifeBoardError, LifeCell};
+
+type NodeId = u32;
+
+const DEAD: NodeId = 0;
+const ALIVE: NodeId = 1;
+const MIN_ROOT_LEVEL: u8 = 3;
+const MAX_ROOT_LEVEL: u8 = 63;  // Largest root whose corners, at -2^62 and 2^62, still fit in an i64
+
+#[derive(Clone, Copy)]
+struct Node {
+    level: u8,
+    /// The quadrants in the order north west, north east, south west, south east; unused by the two leaves.
+    children: [NodeId; 4],
+    population: u64,
+}
+
+// An unbounded B3/S23 universe stored as a quadtree of interned nodes, where equal regions share one node and
+// every node remembers where its center ends up, so repetitive patterns can be stepped 2^k generations at a time.
+// The root of level `L` covers `[-2^(L-1), 2^(L-1))` on both axes, up to `[-2^62, 2^62)`. Nodes are never freed.
+#[derive(Clone)]
+pub struct HashLifeBoard {
+    nodes: Vec<Node>,
+    canonical: HashMap<[NodeId; 4], NodeId>,
+    /// The empty node of every level so far, indexed by level.
+    empty: Vec<NodeId>,
+    /// The centered child of a node advanced 2^j generations, keyed by the node and `j`.
+    successors: HashMap<(NodeId, u8), NodeId>,
+    root: NodeId,
+    generation: u64,
+} impl HashLifeBoard {
+    pub fn new() -> HashLifeBoard {
+        let leaf = |population| Node { level: 0, children: [DEAD; 4], population };
+        let mut board = HashLifeBoard {
+            nodes: vec![leaf(0), leaf(1)], canonical: HashMap::new(), empty: vec![DEAD], successors: HashMap::new(),
+            root: DEAD, generation: 0,
+        };
+        board.root = board._empty(MIN_ROOT_LEVEL);
+        board
+    }
+
+    pub fn from_cells<I: IntoIterator<Item=(i64, i64)>>(cells: I) -> Result<HashLifeBoard, LifeBoardError> {
+        let mut board = HashLifeBoard::new();
+        for (x, y) in cells {
+            board.set_alive(x, y, true)?;
+        }
+        Ok(board)
+    }
+
+    // Places the board's top-left corner at the origin.
+    pub fn from_board<T: LifeCell>(board: &BaseLifeBoard<T>) -> HashLifeBoard {
+        HashLifeBoard::from_cells((0..board.width()).flat_map(|x|
+            (0..board.height()).filter(move |&y| board.is_cell_alive(x, y) == Ok(true)).map(move |y| (x as i64, y as i64))
+        )).expect("Should always fit a board held in memory")
+    }
+
+    pub fn population(&self) -> u64 { self.nodes[self.root as usize].population }
+
+    pub fn generation(&self) -> u64 { self.generation }
+
+    pub fn is_alive(&self, x: i64, y: i64) -> bool {
+        match self._root_coordinates(x, y) {
+            Some((x, y)) => self._is_alive(self.root, x, y),
+            None => false,
+        }
+    }
+
+    pub fn set_alive(&mut self, x: i64, y: i64, alive: bool) -> Result<(), LifeBoardError> {
+        let (x, y) = loop {
+            match self._root_coordinates(x, y) {
+                Some(coordinates) => break coordinates,
+                None if self._try_expand() => (),
+                None => return Err(LifeBoardError::InvalidIndex(
+                    format!("Cell ({x}, {y}) is outside of the [-2^62, 2^62) universe.")
+                )),
+            }
+        };
+        self.root = self._set_alive(self.root, x, y, alive);
+        Ok(())
+    }
+
+    // Returns the inclusive `((min_x, min_y), (max_x, max_y))` corners of the live cells.
+    pub fn bounds(&self) -> Option<((i64, i64), (i64, i64))> {
+        let half = self._half_size();
+        self._bounds(self.root, -half, -half)
+    }
+
+    pub fn simulate(&mut self) -> Result<(), LifeBoardError> { self.simulate_power_of_two_steps(0) }
+
+    // Advances 2^k generations in one pass over the tree. Fails without changing the board when the universe can't
+    // grow large enough, so `k` has to stay below 61 and the live cells within 2^60 of the origin.
+    pub fn simulate_power_of_two_steps(&mut self, k: u8) -> Result<(), LifeBoardError> {
+        let root = self.root;
+        let too_large = |board: &mut HashLifeBoard| {
+            board.root = root;
+            LifeBoardError::InvalidIndex(format!("Stepping 2^{k} generations would need more than the [-2^62, 2^62) universe."))
+        };
+        let Some(generation) = 1u64.checked_shl(k.into()).and_then(|steps| self.generation.checked_add(steps)) else {
+            return Err(too_large(self))
+        };
+        // Once all cells sit in the central half, one more ring of empty space leaves room for them to travel 2^k
+        // cells in any direction, and the successor of the root is the central half again.
+        while self._level(self.root) < k.saturating_add(2) || !self._is_padded() {
+            if !self._try_expand() {
+                return Err(too_large(self))
+            }
+        }
+        if !self._try_expand() {
+            return Err(too_large(self))
+        }
+        self.root = self._successor(self.root, k);
+        self.generation = generation;
+        Ok(())
+    }
+
+    // Splits `n` into powers of two, so the number of passes grows with the bits of `n` rather than with `n`.
+    pub fn simulate_n_steps(&mut self, n: u64) -> Result<(), LifeBoardError> {
+        for k in (0..u64::BITS as u8).filter(|&k| n >> k & 1 == 1) {
+            self.simulate_power_of_two_steps(k)?;
+        }
+        Ok(())
+    }
+
+    // The `width` x `height` cells with their top-left corner at `(x, y)`.
+    pub fn window<T: LifeCell>(&self, x: i64, y: i64, width: usize, height: usize, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        BaseLifeBoard::from_bool_matrix(
+            (x..x + width as i64).map(|x| (y..y + height as i64).map(move |y| self.is_alive(x, y))),
+            init
+        )
+    }
+
+    // Crops to the bounding box of the live cells, or a 1x1 dead board when there are none. Patterns that have spread
+    // too far to allocate, like a gun after millions of generations, are an error; `window` still reads their parts.
+    pub fn to_board<T: LifeCell>(&self, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds().unwrap_or(((0, 0), (0, 0)));
+        let (width, height) = ((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize);
+        life_format::check_decoded_size(width, height)
+            .map_err(|reason| LifeBoardError::InvalidBoard(format!("HashLife bounding box is too large: {reason}.")))?;
+        self.window(min_x, min_y, width, height, init)
+    }
+
+    fn _level(&self, node: NodeId) -> u8 { self.nodes[node as usize].level }
+
+    fn _population(&self, node: NodeId) -> u64 { self.nodes[node as usize].population }
+
+    fn _children(&self, node: NodeId) -> [NodeId; 4] { self.nodes[node as usize].children }
+
+    fn _half_size(&self) -> i64 { 1 << (self._level(self.root) - 1) }
+
+    // Coordinates relative to the root's top-left corner, or `None` outside of the root.
+    fn _root_coordinates(&self, x: i64, y: i64) -> Option<(u64, u64)> {
+        let half = self._half_size();
+        let range = -half..half;
+        (range.contains(&x) && range.contains(&y)).then(|| ((x + half) as u64, (y + half) as u64))
+    }
+
+    fn _join(&mut self, children: [NodeId; 4]) -> NodeId {
+        if let Some(&node) = self.canonical.get(&children) {
+            return node
+        }
+        let level = self._level(children[0]) + 1;
+        let population = children.iter().map(|&child| self._population(child)).sum();
+        let node = self.nodes.len() as NodeId;
+        self.nodes.push(Node { level, children, population });
+        self.canonical.insert(children, node);
+        node
+    }
+
+    fn _empty(&mut self, level: u8) -> NodeId {
+        while self.empty.len() <= level as usize {
+            let smaller = self.empty[self.empty.len() - 1];
+            let empty = self._join([smaller; 4]);
+            self.empty.push(empty);
+        }
+        self.empty[level as usize]
+    }
+
+    // Doubles the root around the origin, surrounding the current root with empty space, or returns false once the
+    // root is as large as it can get. Expanding only adds dead cells, so giving up part way never changes the pattern.
+    fn _try_expand(&mut self) -> bool {
+        if self._level(self.root) >= MAX_ROOT_LEVEL {
+            return false
+        }
+        let [nw, ne, sw, se] = self._children(self.root);
+        let empty = self._empty(self._level(self.root) - 1);
+        let children = [
+            self._join([empty, empty, empty, nw]),
+            self._join([empty, empty, ne, empty]),
+            self._join([empty, sw, empty, empty]),
+            self._join([se, empty, empty, empty]),
+        ];
+        self.root = self._join(children);
+        true
+    }
+
+    // Whether every live cell is inside the central half of the root.
+    fn _is_padded(&self) -> bool {
+        let [nw, ne, sw, se] = self._children(self.root);
+        self._population(nw) == self._population(self._children(nw)[3])
+            && self._population(ne) == self._population(self._children(ne)[2])
+            && self._population(sw) == self._population(self._children(sw)[1])
+            && self._population(se) == self._population(self._children(se)[0])
+    }
+
+    fn _is_alive(&self, node: NodeId, x: u64, y: u64) -> bool {
+        let level = self._level(node);
+        if level == 0 {
+            return node == ALIVE
+        }
+        let half = 1 << (level - 1);
+        let quadrant = (x >= half) as usize + 2 * (y >= half) as usize;
+        self._is_alive(self._children(node)[quadrant], x % half, y % half)
+    }
+
+    fn _set_alive(&mut self, node: NodeId, x: u64, y: u64, alive: bool) -> NodeId {
+        let level = self._level(node);
+        if level == 0 {
+            return if alive { ALIVE } else { DEAD }
+        }
+        let half = 1 << (level - 1);
+        let quadrant = (x >= half) as usize + 2 * (y >= half) as usize;
+        let mut children = self._children(node);
+        children[quadrant] = self._set_alive(children[quadrant], x % half, y % half, alive);
+        self._join(children)
+    }
+
+    fn _bounds(&self, node: NodeId, left: i64, top: i64) -> Option<((i64, i64), (i64, i64))> {
+        let level = self._level(node);
+        if self._population(node) == 0 {
+            return None
+        } else if level == 0 {
+            return Some(((left, top), (left, top)))
+        }
+        let half = 1 << (level - 1);
+        self._children(node).iter().enumerate()
+            .filter_map(|(quadrant, &child)| self._bounds(child, left + half * (quadrant % 2) as i64, top + half * (quadrant / 2) as i64))
+            .reduce(|((min_x, min_y), (max_x, max_y)), ((x0, y0), (x1, y1))|
+                ((min_x.min(x0), min_y.min(y0)), (max_x.max(x1), max_y.max(y1)))
+            )
+    }
+
+    // The centered child of a level 2 node (4x4 cells) after one generation.
+    fn _step_4x4(&mut self, node: NodeId) -> NodeId {
+        let mut cells = [[false; 4]; 4];
+        for (x, column) in cells.iter_mut().enumerate() {
+            for (y, cell) in column.iter_mut().enumerate() {
+                *cell = self._is_alive(node, x as u64, y as u64);
+            }
+        }
+        let next = |x: usize, y: usize| {
+            let neighbors = (x - 1..=x + 1).flat_map(|nx| (y - 1..=y + 1).map(move |ny| (nx, ny)))
+                .filter(|&(nx, ny)| (nx, ny) != (x, y) && cells[nx][ny])
+                .count();
+            if neighbors == 3 || (neighbors == 2 && cells[x][y]) { ALIVE } else { DEAD }
+        };
+        let children = [next(1, 1), next(2, 1), next(1, 2), next(2, 2)];
+        self._join(children)
+    }
+
+    // The centered child of `node` after 2^j generations, for `j` up to the node's level minus two. The result only
+    // depends on the node itself, so it is computed once per node and `j`.
+    fn _successor(&mut self, node: NodeId, j: u8) -> NodeId {
+        let level = self._level(node);
+        let j = j.min(level - 2);
+        if self._population(node) == 0 {
+            return self._empty(level - 1)
+        } else if let Some(&successor) = self.successors.get(&(node, j)) {
+            return successor
+        }
+        let successor = if level == 2 {
+            self._step_4x4(node)
+        } else {
+            let [nw, ne, sw, se] = self._children(node);
+            let [_, nw_ne, nw_sw, nw_se] = self._children(nw);
+            let [ne_nw, _, ne_sw, ne_se] = self._children(ne);
+            let [sw_nw, sw_ne, _, sw_se] = self._children(sw);
+            let [se_nw, se_ne, se_sw, _] = self._children(se);
+            // The nine overlapping sub-squares of half the size, row by row.
+            let squares = [
+                nw,
+                self._join([nw_ne, ne_nw, nw_se, ne_sw]),
+                ne,
+                self._join([nw_sw, nw_se, sw_nw, sw_ne]),
+                self._join([nw_se, ne_sw, sw_ne, se_nw]),
+                self._join([ne_sw, ne_se, se_nw, se_ne]),
+                sw,
+                self._join([sw_ne, se_nw, sw_se, se_sw]),
+                se,
+            ];
+            let mut centers = [DEAD; 9];
+            for (center, square) in centers.iter_mut().zip(squares) {
+                *center = self._successor(square, j);
+            }
+            let quadrants = [[0, 1, 3, 4], [1, 2, 4, 5], [3, 4, 6, 7], [4, 5, 7, 8]];
+            let mut children = [DEAD; 4];
+            for (child, [a, b, c, d]) in children.iter_mut().zip(quadrants) {
+                *child = if j < level - 2 {
+                    // The centers have already advanced the full 2^j generations, so only their middle is kept.
+                    let middle = [
+                        self._children(centers[a])[3], self._children(centers[b])[2],
+                        self._children(centers[c])[1], self._children(centers[d])[0],
+                    ];
+                    self._join(middle)
+                } else {
+                    let square = self._join([centers[a], centers[b], centers[c], centers[d]]);
+                    self._successor(square, j)
+                };
+            }
+            self._join(children)
+        };
+        self.successors.insert((node, j), successor);
+        successor
+    }
+} impl Default for HashLifeBoard {
+    fn default() -> Self { HashLifeBoard::new() }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life_hashlife::HashLifeBoard;
+    use crate::life_implementation::{BaseLifeBoard, Cell};
+    use crate::life_interface::{LifeBoard, LifeBoardError};
+    use crate::life_sparse::SparseLifeBoard;
+    use crate::patterns;
+
+    // Wide enough that nothing within 64 generations of the soup can reach the dead edge of the base board.
+    const MARGIN: usize = 66;
+
+    #[test]
+    fn test_equivalence_hashlife_matches_base_board_random_soups() {
+        let size = 64 + 2 * MARGIN;
+        for seed in 0..2 {
+            let soup = BaseLifeBoard::gen_seeded(64, 64, 0.35, seed, Cell::from_bool);
+            let mut hashlife_board = HashLifeBoard::from_board(&soup);
+            let mut base_board = soup.clone();
+            base_board.resize(size, size, false).unwrap();
+            base_board.translate(MARGIN as i64, MARGIN as i64, false);
+            let mut base_generations = vec![base_board.clone()];
+            for n in 1..=64 {
+                base_board.simulate();
+                hashlife_board.simulate().unwrap();
+                let window = hashlife_board.window(-(MARGIN as i64), -(MARGIN as i64), size, size, Cell::from_bool);
+                assert_eq!(base_board, window.unwrap(), "Seed {seed}, step {n}");
+                assert_eq!((n, base_board.count_alive() as u64), (hashlife_board.generation(), hashlife_board.population()));
+                base_generations.push(base_board.clone());
+            }
+            // Jumping straight there takes one pass per set bit instead of one per generation.
+            for n in [2, 7, 16, 37, 64] {
+                let mut board = HashLifeBoard::from_board(&soup);
+                board.simulate_n_steps(n as u64).unwrap();
+                let window = board.window(-(MARGIN as i64), -(MARGIN as i64), size, size, Cell::from_bool);
+                assert_eq!(base_generations[n], window.unwrap(), "Seed {seed}, jump of {n}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_equivalence_hashlife_gosper_gun_matches_sparse() {
+        let gun = patterns::gosper_glider_gun(Cell::from_bool);
+        let mut hashlife_board = HashLifeBoard::from_board(&gun);
+        let mut sparse_board = SparseLifeBoard::from_board(&gun);
+        hashlife_board.simulate_power_of_two_steps(10).unwrap();
+        sparse_board.simulate_n_steps(1024);
+        assert_eq!(sparse_board.population() as u64, hashlife_board.population());
+        assert_eq!(sparse_board.bounds(), hashlife_board.bounds());
+        assert_eq!(sparse_board.to_board(Cell::from_bool), hashlife_board.to_board(Cell::from_bool).unwrap());
+    }
+
+    #[test]
+    fn test_equivalence_hashlife_glider_far_away() {
+        let glider = patterns::glider(Cell::from_bool);
+        let mut board = HashLifeBoard::from_board(&glider);
+        board.simulate_n_steps(1 << 20).unwrap();
+        assert_eq!((5, 1 << 20), (board.population(), board.generation()));
+        let shift = (1 << 20) / 4;
+        assert_eq!(Some(((shift, -shift), (2 + shift, 2 - shift))), board.bounds());
+        assert_eq!(glider, board.to_board(Cell::from_bool).unwrap());
+    }
+
+    #[test]
+    fn test_equivalence_hashlife_set_alive_negative_coordinates() {
+        let mut board = HashLifeBoard::new();
+        board.set_alive(-1000, 37, true).unwrap();
+        board.set_alive(5, -3, true).unwrap();
+        board.set_alive(5, -3, false).unwrap();
+        assert!(board.is_alive(-1000, 37) && !board.is_alive(5, -3) && !board.is_alive(i64::MAX, 0));
+        assert_eq!((1, Some(((-1000, 37), (-1000, 37)))), (board.population(), board.bounds()));
+    }
+
+    #[test]
+    fn test_boundary_hashlife_empty_board() {
+        let mut board = HashLifeBoard::new();
+        board.simulate_n_steps(1000).unwrap();
+        assert_eq!((0, 1000, None), (board.population(), board.generation(), board.bounds()));
+        assert_eq!(BaseLifeBoard::from_bool_matrix([[false]], Cell::from_bool).unwrap(), board.to_board(Cell::from_bool).unwrap());
+    }
+
+    #[test]
+    fn test_exception_hashlife_coordinates_outside_of_universe() {
+        let mut board = HashLifeBoard::new();
+        for (x, y) in [(i64::MAX, 0), (0, i64::MIN), (1 << 62, 0)] {
+            match board.set_alive(x, y, true) {
+                Err(LifeBoardError::InvalidIndex(error)) => assert!(error.contains("universe"), "{error}"),
+                result => panic!("Expected an InvalidIndex error but found {result:?}"),
+            }
+        }
+        board.set_alive(-(1 << 62), (1 << 62) - 1, true).unwrap();
+        assert!(HashLifeBoard::from_cells([(0, 0), (i64::MIN, 0)]).is_err());
+    }
+
+    #[test]
+    fn test_exception_hashlife_too_many_steps() {
+        let mut board = HashLifeBoard::from_board(&patterns::glider(Cell::from_bool));
+        for k in [61, 64, u8::MAX] {
+            match board.simulate_power_of_two_steps(k) {
+                Err(LifeBoardError::InvalidIndex(error)) => assert!(error.contains("universe"), "{error}"),
+                result => panic!("Expected an InvalidIndex error but found {result:?}"),
+            }
+        }
+        assert_eq!((0, 5), (board.generation(), board.population()));
+        board.simulate_power_of_two_steps(60).unwrap();
+        assert_eq!((1 << 60, 5), (board.generation(), board.population()));
+    }
+
+    #[test]
+    fn test_exception_hashlife_to_board_too_large() {
+        let board = HashLifeBoard::from_cells([(0, 0), (1 << 20, 1 << 20)]).unwrap();
+        match board.to_board(Cell::from_bool) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("too large"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+        assert_eq!(1, board.window(1 << 20, 1 << 20, 1, 1, Cell::from_bool).unwrap().count_alive());
+    }
+
+    #[test]
+    fn test_exception_hashlife_empty_window() {
+        match HashLifeBoard::new().window(0, 0, 0, 3, Cell::from_bool) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("wide"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+    }
+}
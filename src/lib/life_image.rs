@@ -0,0 +1,171 @@
+use std::path::Path;
+use image::imageops::FilterType;
+use image::{DynamicImage, Rgba, RgbaImage};
+use crate::life_implementation::BaseLifeBoard;
+use crate::life_interface::{LifeBoard, LifeBoardError, LifeCell};
+
+const ALIVE_PIXEL: Rgba<u8> = Rgba([0, 0, 0, 0xff]);
+const DEAD_PIXEL: Rgba<u8> = Rgba([0xff, 0xff, 0xff, 0xff]);
+
+// Draws every cell as a `cell_size` square, using the same dark-is-alive convention as `from_image`.
+pub(crate) fn rasterize<T: LifeCell>(board: &impl LifeBoard<T>, cell_size: u32) -> RgbaImage {
+    RgbaImage::from_fn(board.width() as u32 * cell_size, board.height() as u32 * cell_size, |x, y| {
+        match board.is_cell_alive((x / cell_size) as usize, (y / cell_size) as usize) {
+            Ok(true) => ALIVE_PIXEL,
+            _ => DEAD_PIXEL,
+        }
+    })
+}
+
+// Writes `generations` frames, starting with the board's current state and simulating between frames.
+#[cfg(feature = "gif")]
+pub fn export_gif<T: LifeCell, P: AsRef<Path>>(board: &mut impl LifeBoard<T>, path: P, generations: usize, cell_size: u32, frame_delay_ms: u16) -> Result<(), LifeBoardError> {
+    use image::codecs::gif::{GifEncoder, Repeat};
+    use image::{Delay, Frame};
+    let path = path.as_ref();
+    let io_error = |error: &dyn std::fmt::Display| LifeBoardError::Io(format!("Could not write GIF {}: {error}", path.display()));
+    if cell_size == 0 {
+        return Err(LifeBoardError::InvalidBoard(String::from("GIF cells must be at least one pixel wide.")));
+    }
+    let file = std::fs::File::create(path).map_err(|error| io_error(&error))?;
+    let mut encoder = GifEncoder::new(std::io::BufWriter::new(file));
+    encoder.set_repeat(Repeat::Infinite).map_err(|error| io_error(&error))?;
+    let delay = Delay::from_numer_denom_ms(frame_delay_ms as u32, 1);
+    for generation in 0..generations {
+        if generation > 0 {
+            board.simulate();
+        }
+        let frame = Frame::from_parts(rasterize(board, cell_size), 0, 0, delay);
+        encoder.encode_frame(frame).map_err(|error| io_error(&error))?;
+    }
+    Ok(())
+}
+
+impl<T: LifeCell> BaseLifeBoard<T> {
+    pub fn to_image(&self, cell_size: u32) -> RgbaImage {
+        rasterize(self, cell_size)
+    }
+
+    pub fn from_image<P: AsRef<Path>>(path: P, threshold: u8, size: Option<(usize, usize)>, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)
+            .map_err(|error| LifeBoardError::Io(format!("Could not read image {}: {error}", path.display())))?;
+        BaseLifeBoard::from_image_bytes(&bytes, threshold, size, init)
+    }
+
+    pub fn from_image_bytes(bytes: &[u8], threshold: u8, size: Option<(usize, usize)>, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        let image = image::load_from_memory(bytes)
+            .map_err(|error| LifeBoardError::Decode(format!("Could not decode image: {error}")))?;
+        BaseLifeBoard::_from_image(image, threshold, size, init)
+    }
+
+    fn _from_image(image: DynamicImage, threshold: u8, size: Option<(usize, usize)>, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        let image = match size {
+            Some((width, height)) => {
+                let (width, height) = (u32::try_from(width), u32::try_from(height));
+                let (Ok(width), Ok(height)) = (width, height) else {
+                    return Err(LifeBoardError::InvalidBoard("Image target size is too large.".to_string()));
+                };
+                image.resize_exact(width, height, FilterType::Nearest)
+            },
+            None => image,
+        }.to_luma8();
+        // Pixels darker than the threshold become alive cells.
+        let image = &image;
+        BaseLifeBoard::from_bool_matrix(
+            (0..image.width()).map(|x| (0..image.height()).map(move |y| image.get_pixel(x, y).0[0] < threshold)),
+            init
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use image::{GrayImage, ImageOutputFormat, Luma};
+    use crate::life_implementation::{BaseLifeBoard, Cell};
+    use crate::life_interface::{LifeBoard, LifeBoardError};
+
+    // Encodes a 4x4 checkerboard made of 2x2 black and white squares.
+    fn get_checkerboard_png() -> Vec<u8> {
+        let image = GrayImage::from_fn(4, 4, |x, y| if (x / 2 + y / 2) % 2 == 0 { Luma([0]) } else { Luma([255]) });
+        let mut bytes = Cursor::new(Vec::new());
+        image.write_to(&mut bytes, ImageOutputFormat::Png).unwrap();
+        bytes.into_inner()
+    }
+
+    #[test]
+    fn test_equivalence_from_image_bytes_checkerboard() {
+        let board = BaseLifeBoard::from_image_bytes(&get_checkerboard_png(), 128, None, Cell::from_bool).unwrap();
+        let expected_board = BaseLifeBoard::from_bool_matrix([
+            [true, true, false, false],
+            [true, true, false, false],
+            [false, false, true, true],
+            [false, false, true, true],
+        ], Cell::from_bool).unwrap();
+        assert_eq!(expected_board, board);
+    }
+
+    #[test]
+    fn test_equivalence_from_image_bytes_downscaled_checkerboard() {
+        let board = BaseLifeBoard::from_image_bytes(&get_checkerboard_png(), 128, Some((2, 2)), Cell::from_bool).unwrap();
+        let expected_board = BaseLifeBoard::from_bool_matrix([
+            [true, false],
+            [false, true],
+        ], Cell::from_bool).unwrap();
+        assert_eq!(expected_board, board);
+    }
+
+    #[test]
+    fn test_boundary_from_image_bytes_zero_threshold_is_empty() {
+        let board = BaseLifeBoard::from_image_bytes(&get_checkerboard_png(), 0, None, Cell::from_bool).unwrap();
+        assert_eq!(0, board.count_alive());
+    }
+
+    #[test]
+    fn test_exception_from_image_bytes_invalid_data() {
+        match BaseLifeBoard::from_image_bytes(b"not an image", 128, None, Cell::from_bool) {
+            Err(LifeBoardError::Decode(_)) => (),
+            result => panic!("Expected a decode error but got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_equivalence_to_image_from_image_round_trip() {
+        let board = BaseLifeBoard::from_rle("x = 3, y = 3\nbo$2bo$3o!", Cell::from_bool).unwrap();
+        let mut bytes = Cursor::new(Vec::new());
+        board.to_image(3).write_to(&mut bytes, ImageOutputFormat::Png).unwrap();
+        let decoded_board = BaseLifeBoard::from_image_bytes(&bytes.into_inner(), 128, Some((3, 3)), Cell::from_bool).unwrap();
+        assert_eq!(board, decoded_board);
+    }
+
+    #[cfg(feature = "gif")]
+    #[test]
+    fn test_equivalence_export_gif_blinker_10_frames() {
+        use std::fs::File;
+        use image::AnimationDecoder;
+        use image::codecs::gif::GifDecoder;
+        use crate::life_implementation::ParallelLifeBoard;
+        let mut board = ParallelLifeBoard::from_board(
+            BaseLifeBoard::from_rle("x = 5, y = 5\n$$b3o!", Cell::from_bool).unwrap(),
+            2
+        );
+        let path = std::env::temp_dir().join(format!("life_blinker_{}.gif", std::process::id()));
+        crate::export_gif(&mut board, &path, 10, 4, 100).unwrap();
+        let frames = GifDecoder::new(File::open(&path).unwrap()).unwrap().into_frames().collect_frames().unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(10, frames.len());
+        assert_eq!((20, 20), frames[0].buffer().dimensions());
+        assert_ne!(frames[0].buffer(), frames[1].buffer());
+        assert_eq!(frames[0].buffer(), frames[2].buffer());
+        assert_eq!(3, board.count_alive());
+    }
+
+    #[test]
+    fn test_exception_from_image_missing_file() {
+        match BaseLifeBoard::from_image("does/not/exist.png", 128, None, Cell::from_bool) {
+            Err(LifeBoardError::Io(message)) => assert!(message.contains("does/not/exist.png"), "{message}"),
+            result => panic!("Expected an io error but got {result:?}"),
+        }
+    }
+}
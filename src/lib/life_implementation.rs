@@ -1,17 +1,24 @@
 use std::fmt::{Debug, Display, Formatter};
-use std::ops::Range;
-use std::sync::{Arc, mpsc};
-use std::{fmt, thread};
-use rand::Rng;
-use crate::life_interface::{LifeBoard, LifeBoardError, LifeCell};
+use std::hash::{Hash, Hasher};
+use std::ops::{BitAnd, BitOr, BitXor, Index, Not, Range};
+use std::any::Any;
+use std::{fmt, mem, thread};
+#[cfg(not(feature = "parallel"))]
+use std::panic::{self, AssertUnwindSafe};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use crate::life_interface;
+use crate::life_quadtree::QuadTree;
+use crate::life_interface::{LifeBoard, LifeBoardError, LifeCell, PopulationStatistics, StepStats};
 
-#[derive(PartialEq, Clone)]
+#[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cell { alive: bool } impl Cell {
     pub fn gen() -> Cell { Cell { alive: rand::thread_rng().gen_bool(0.5) } }
 
-    pub fn from_bool(alive: bool) -> Cell { Cell { alive } }
+    pub const fn from_bool(alive: bool) -> Cell { Cell { alive } }
 
-} impl LifeCell<Cell> for Cell {
+} impl LifeCell for Cell {
     fn is_alive(&self) -> bool { self.alive }
     fn to_alive(&self) -> Cell {
         Cell { alive: true }
@@ -21,26 +28,107 @@ pub struct Cell { alive: bool } impl Cell {
     }
 }
 
-#[derive(PartialEq, Clone)]
-pub struct BaseLifeBoard<T: LifeCell<T>> {
-    grid: Vec<Vec<T>>,
+// Plain `bool` cells need no wrapper; pass `std::convert::identity` wherever an `init` function is expected.
+impl LifeCell for bool {
+    fn is_alive(&self) -> bool { *self }
+    fn to_alive(&self) -> bool { true }
+    fn to_dead(&self) -> bool { false }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BoolOp { Or, And, Xor } impl BoolOp {
+    fn apply(&self, a: bool, b: bool) -> bool {
+        match self {
+            BoolOp::Or => a || b,
+            BoolOp::And => a && b,
+            BoolOp::Xor => a != b,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Neighborhood {
+    #[default]
+    Moore,
+    VonNeumann,
+    MooreRadius(u8),
+} impl Neighborhood {
+    // Neighbor counts are `u8`s, which hold the (2r + 1)^2 - 1 cells around a `MooreRadius` of up to 7.
+    pub const MAX_RADIUS: u8 = 7;
+
+    pub(crate) fn radius(&self) -> i64 {
+        match self {
+            Neighborhood::Moore | Neighborhood::VonNeumann => 1,
+            Neighborhood::MooreRadius(radius) => *radius as i64,
+        }
+    }
+
+    fn contains(&self, dx: i64, dy: i64) -> bool {
+        match self {
+            Neighborhood::VonNeumann => dx.abs() + dy.abs() <= 1,
+            _ => true,
+        }
+    }
+}
+
+// Panic payloads are almost always the `&str` or `String` passed to `panic!`.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload.downcast_ref::<&str>().copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
+
+// Which axis `ParallelLifeBoard` divides between its threads: `Rows` hands each thread a range of x
+// (suits wide boards), `Columns` a range of y (suits tall boards).
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SplitAxis {
+    #[default]
+    Rows,
+    Columns,
+}
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(
+    try_from = "SerializedLifeBoard<T>",
+    into = "SerializedLifeBoard<T>",
+    bound(serialize = "T: serde::Serialize", deserialize = "T: serde::Deserialize<'de>")
+))]
+pub struct BaseLifeBoard<T: LifeCell> {
+    /// Every cell in one allocation, column by column, at index `x * height + y`.
+    cells: Vec<T>,
     width: usize,
     height: usize,
-} impl <T: LifeCell<T>> BaseLifeBoard<T> {
-    fn from_bool_matrix<A, B>(collection: A, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError>
+    neighborhood: Neighborhood,
+    /// Whether neighbor lookups wrap around the left and right edges.
+    wrap_x: bool,
+    /// Whether neighbor lookups wrap around the top and bottom edges.
+    wrap_y: bool,
+    /// When set, every step pads the board so live cells stay at least this many cells away from its edges.
+    auto_expand: Option<usize>,
+    /// The next generation is written here and swapped into `cells`, so stepping reuses one allocation.
+    back_cells: Vec<T>,
+    /// Boards with at least this many cells count neighbors through a quadtree of the live cells while stepping.
+    quadtree_threshold: Option<usize>,
+    /// Only present during a step, so edits between steps can never leave it stale.
+    quadtree: Option<QuadTree>,
+} impl <T: LifeCell> BaseLifeBoard<T> {
+    // The outer collection holds the columns, so `collection[x][y]` is the cell at `(x, y)`. Every column must have
+    // the same, non-zero length.
+    pub fn from_bool_matrix<A, B>(collection: A, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError>
         where
             A: IntoIterator<Item=B>,
             B: IntoIterator<Item=bool>
     {
         let grid = collection.into_iter().map(|row|
-            row.into_iter().map(|alive|
-                init(alive)
-            ).collect()
+            row.into_iter().map(init).collect()
         ).collect();
-        return BaseLifeBoard::_from_grid(grid);
+        BaseLifeBoard::_from_grid(grid)
     }
 
-    fn from_cell_matrix<A, B>(collection: A) -> Result<BaseLifeBoard<T>, LifeBoardError>
+    pub fn from_cell_matrix<A, B>(collection: A) -> Result<BaseLifeBoard<T>, LifeBoardError>
         where
             A: IntoIterator<Item=B>,
             B: IntoIterator<Item=T>
@@ -48,9 +136,27 @@ pub struct BaseLifeBoard<T: LifeCell<T>> {
         let grid = collection.into_iter().map(|row|
             row.into_iter().collect()
         ).collect();
-        return BaseLifeBoard::_from_grid(grid);
+        BaseLifeBoard::_from_grid(grid)
+    }
+
+    // The counterpart of `rows`, keeping each row vector as it is. `FromIterator` can't be implemented for the
+    // `Result` here, so this is the way to build a board straight from iterator output.
+    pub fn try_from_rows<I: IntoIterator<Item=Vec<T>>>(rows: I) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        BaseLifeBoard::_from_grid(rows.into_iter().collect())
+    }
+
+    // Reshapes row-major cells, as used by FFI and GPU buffers, into the column-major grid.
+    pub fn from_flat(cells: &[bool], width: usize, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            return Err(LifeBoardError::InvalidBoard(
+                format!("{} cells cannot be split into rows of width {width}.", cells.len())
+            ));
+        }
+        let height = cells.len() / width;
+        BaseLifeBoard::from_bool_matrix((0..width).map(|x| (0..height).map(move |y| cells[y * width + x])), init)
     }
 
+    // Flattens the column vectors accepted at the API boundary into the internal storage.
     fn _from_grid(grid: Vec<Vec<T>>) -> Result<BaseLifeBoard<T>, LifeBoardError> {
         let width = match grid.len() {
             0 => return Err(
@@ -71,148 +177,670 @@ pub struct BaseLifeBoard<T: LifeCell<T>> {
                 )
             }
         }
-        return Ok(BaseLifeBoard { grid, width, height })
+        Ok(BaseLifeBoard::_new(grid.into_iter().flatten().collect(), width, height))
+    }
+
+    fn _new(cells: Vec<T>, width: usize, height: usize) -> BaseLifeBoard<T> {
+        BaseLifeBoard {
+            cells, width, height, neighborhood: Neighborhood::default(), wrap_x: false, wrap_y: false, auto_expand: None,
+            back_cells: Vec::new(), quadtree_threshold: None, quadtree: None,
+        }
+    }
+
+    // A board with the same neighborhood and wrapping as this one but different cells.
+    fn _with_cells(&self, cells: Vec<T>, width: usize, height: usize) -> BaseLifeBoard<T> {
+        BaseLifeBoard {
+            cells, width, height, neighborhood: self.neighborhood, wrap_x: self.wrap_x, wrap_y: self.wrap_y,
+            auto_expand: self.auto_expand, back_cells: Vec::new(), quadtree_threshold: self.quadtree_threshold, quadtree: None,
+        }
     }
 
+    fn _index(&self, x: usize, y: usize) -> usize { x * self.height + y }
+
+    fn _columns(&self) -> Rows<'_, T> { Rows { cells: &self.cells, height: self.height, x_range: 0..self.width } }
+
     pub fn gen(width: usize, height: usize, gen: fn() -> T) -> BaseLifeBoard<T> {
-        let mut grid: Vec<Vec<T>> = Vec::with_capacity(width);
-        for _ in 0..width {
-            let mut col = Vec::with_capacity(height);
-            for _ in 0..height {
-                col.push(gen());
+        BaseLifeBoard::_new((0..width * height).map(|_| gen()).collect(), width, height)
+    }
+
+    // `density` is the chance of each cell starting alive and is clamped to [0, 1].
+    pub fn gen_with_density(width: usize, height: usize, density: f64, init: fn(state: bool) -> T) -> BaseLifeBoard<T> {
+        BaseLifeBoard::gen_with_rng(width, height, density, &mut rand::thread_rng(), init)
+    }
+
+    // Always produces the same board for the same arguments, e.g. to reproduce a run.
+    pub fn gen_seeded(width: usize, height: usize, density: f64, seed: u64, init: fn(state: bool) -> T) -> BaseLifeBoard<T> {
+        BaseLifeBoard::gen_with_rng(width, height, density, &mut SmallRng::seed_from_u64(seed), init)
+    }
+
+    pub fn gen_with_rng<R: Rng>(width: usize, height: usize, density: f64, rng: &mut R, init: fn(state: bool) -> T) -> BaseLifeBoard<T> {
+        // NaN is not a valid probability either, so treat it like an empty board.
+        let density = if density.is_nan() { 0.0 } else { density.clamp(0.0, 1.0) };
+        BaseLifeBoard::_new((0..width * height).map(|_| init(rng.gen_bool(density))).collect(), width, height)
+    }
+
+    pub fn neighborhood(&self) -> Neighborhood { self.neighborhood }
+
+    pub fn set_neighborhood(&mut self, neighborhood: Neighborhood) -> Result<(), LifeBoardError> {
+        match neighborhood {
+            Neighborhood::MooreRadius(radius) if radius > Neighborhood::MAX_RADIUS => Err(LifeBoardError::InvalidBoard(format!(
+                "Moore radius {radius} has more neighbors than can be counted, the largest radius is {}.", Neighborhood::MAX_RADIUS
+            ))),
+            neighborhood => {
+                self.neighborhood = neighborhood;
+                Ok(())
+            },
+        }
+    }
+
+    pub fn wrap_x(&self) -> bool { self.wrap_x }
+
+    pub fn wrap_y(&self) -> bool { self.wrap_y }
+
+    // Wrapping both axes makes the board a torus, wrapping just one a cylinder.
+    pub fn set_wrap(&mut self, wrap_x: bool, wrap_y: bool) {
+        self.wrap_x = wrap_x;
+        self.wrap_y = wrap_y;
+    }
+
+    pub fn resize(&mut self, new_width: usize, new_height: usize, fill: bool) -> Result<(), LifeBoardError> {
+        if new_width == 0 {
+            return Err(LifeBoardError::InvalidBoard(String::from("Board must be at least one cell wide.")));
+        }
+        if new_height == 0 {
+            return Err(LifeBoardError::InvalidBoard(String::from("Board must be at least one cell tall.")));
+        }
+        let template = self.cells.first().ok_or_else(|| LifeBoardError::InvalidBoard(
+            format!("The {}x{} board has no cell to fill the resized board with.", self.width, self.height)
+        ))?;
+        let fill_cell = if fill { template.to_alive() } else { template.to_dead() };
+        let mut cells = Vec::with_capacity(new_width * new_height);
+        for col in self._columns().take(new_width) {
+            cells.extend(col.iter().take(new_height).cloned());
+            cells.resize(cells.len() + new_height.saturating_sub(self.height), fill_cell.clone());
+        }
+        cells.resize(new_width * new_height, fill_cell);
+        self.cells = cells;
+        self.width = new_width;
+        self.height = new_height;
+        Ok(())
+    }
+
+    pub fn translate(&mut self, dx: i64, dy: i64, wrap: bool) {
+        let (width, height) = (self.width as i64, self.height as i64);
+        let (dx, dy) = if wrap { (dx.rem_euclid(width), dy.rem_euclid(height)) } else { (dx, dy) };
+        let board = &*self;
+        let cells = (0..width).flat_map(|x|
+            (0..height).map(move |y| {
+                let (src_x, src_y) = (x.saturating_sub(dx), y.saturating_sub(dy));
+                let (src_x, src_y) = if wrap { (src_x.rem_euclid(width), src_y.rem_euclid(height)) } else { (src_x, src_y) };
+                match board._cell_at(src_x, src_y) {
+                    Some(cell) => cell.clone(),
+                    None => board.cells[board._index(x as usize, y as usize)].to_dead(),
+                }
+            })
+        ).collect();
+        self.cells = cells;
+    }
+
+    pub fn shift(&mut self, dx: isize, dy: isize) {
+        self.translate(dx as i64, dy as i64, false);
+    }
+
+    pub fn live_bounds(&self) -> Option<(Range<usize>, Range<usize>)> {
+        let mut bounds: Option<(Range<usize>, Range<usize>)> = None;
+        for (x, col) in self._columns().enumerate() {
+            for (y, cell) in col.iter().enumerate() {
+                if cell.is_alive() {
+                    bounds = Some(match bounds {
+                        None => (x..x + 1, y..y + 1),
+                        Some((x_range, y_range)) => (
+                            x_range.start.min(x)..x_range.end.max(x + 1),
+                            y_range.start.min(y)..y_range.end.max(y + 1),
+                        ),
+                    });
+                }
+            }
+        }
+        bounds
+    }
+
+    pub fn trimmed(&self) -> Option<BaseLifeBoard<T>> {
+        let (x_range, y_range) = self.live_bounds()?;
+        Some(self._sub_board(x_range, y_range))
+    }
+
+    // Copies the `w` x `h` rectangle at `(x, y)` into its own board, the read counterpart to `fill_region`.
+    pub fn region(&self, x: usize, y: usize, w: usize, h: usize) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        let (x_range, y_range) = life_interface::region_ranges(self.width, self.height, x, y, w, h)?;
+        if x_range.is_empty() || y_range.is_empty() {
+            return Err(LifeBoardError::InvalidBoard(String::from("Region must be at least one cell wide and tall.")))
+        }
+        Ok(self._sub_board(x_range, y_range))
+    }
+
+    fn _sub_board(&self, x_range: Range<usize>, y_range: Range<usize>) -> BaseLifeBoard<T> {
+        let cells = self._columns().skip(x_range.start).take(x_range.len())
+            .flat_map(|col| col[y_range.clone()].iter().cloned())
+            .collect();
+        self._with_cells(cells, x_range.len(), y_range.len())
+    }
+
+    // Empty boards have no bounding box, so they all share the single dead cell board as their canonical form.
+    // A board without any cells has no cell to build that from.
+    pub fn canonical(&self) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        if let Some(board) = self.trimmed() {
+            return Ok(board);
+        }
+        match self.cells.first() {
+            Some(cell) => Ok(self._with_cells(vec![cell.to_dead()], 1, 1)),
+            None => Err(LifeBoardError::InvalidBoard(format!("The {}x{} board has no canonical form.", self.width, self.height))),
+        }
+    }
+
+    pub fn equivalent_to(&self, other: &BaseLifeBoard<T>) -> bool {
+        self.trimmed() == other.trimmed()
+    }
+
+    pub fn flipped_horizontal(&self) -> BaseLifeBoard<T> {
+        let cells = self._columns().rev().flatten().cloned().collect();
+        self._with_cells(cells, self.width, self.height)
+    }
+
+    pub fn flipped_vertical(&self) -> BaseLifeBoard<T> {
+        let mut board = self.clone();
+        for col in board.cells.chunks_exact_mut(board.height.max(1)) {
+            col.reverse();
+        }
+        board
+    }
+
+    pub fn combine(&self, other: &BaseLifeBoard<T>, op: BoolOp) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        if (self.width, self.height) != (other.width, other.height) {
+            return Err(LifeBoardError::InvalidBoard(format!(
+                "Cannot combine a {}x{} board with a {}x{} board.", self.width, self.height, other.width, other.height
+            )));
+        }
+        let cells = self.cells.iter().zip(&other.cells).map(|(cell, other_cell)|
+            if op.apply(cell.is_alive(), other_cell.is_alive()) { cell.to_alive() } else { cell.to_dead() }
+        ).collect();
+        Ok(self._with_cells(cells, self.width, self.height))
+    }
+
+    // Lists `(x, y, state in other)` for every cell whose state differs, column by column.
+    pub fn diff(&self, other: &BaseLifeBoard<T>) -> Result<Vec<(usize, usize, bool)>, LifeBoardError> {
+        if (self.width, self.height) != (other.width, other.height) {
+            return Err(LifeBoardError::InvalidBoard(format!(
+                "Cannot diff a {}x{} board with a {}x{} board.", self.width, self.height, other.width, other.height
+            )));
+        }
+        Ok(self._columns().zip(other._columns()).enumerate().flat_map(|(x, (col, other_col))|
+            col.iter().zip(other_col).enumerate()
+                .filter(|(_, (cell, other_cell))| cell.is_alive() != other_cell.is_alive())
+                .map(move |(y, (_, other_cell))| (x, y, other_cell.is_alive()))
+        ).collect())
+    }
+
+    pub fn bitwise_or(&self, other: &Self) -> Result<Self, LifeBoardError> { self.combine(other, BoolOp::Or) }
+
+    pub fn bitwise_and(&self, other: &Self) -> Result<Self, LifeBoardError> { self.combine(other, BoolOp::And) }
+
+    pub fn bitwise_xor(&self, other: &Self) -> Result<Self, LifeBoardError> { self.combine(other, BoolOp::Xor) }
+
+    pub fn bitwise_not(&self) -> Self {
+        let cells = self.cells.iter().map(|cell| if cell.is_alive() { cell.to_dead() } else { cell.to_alive() }).collect();
+        self._with_cells(cells, self.width, self.height)
+    }
+
+    pub fn population_statistics(&mut self) -> PopulationStatistics {
+        let stats = self.simulate_stats();
+        PopulationStatistics::from_step(self.width * self.height, self.count_alive(), stats)
+    }
+
+    pub fn statistics_after_step(&self) -> (PopulationStatistics, BaseLifeBoard<T>) {
+        let mut board = self.clone();
+        (board.population_statistics(), board)
+    }
+
+    pub fn simulate_with_rule<F: Fn(bool, u8) -> bool>(&mut self, rule: F) {
+        self._step(|board, x, y| {
+            let old_cell = board.cell_at(x, y)?;
+            let alive = rule(old_cell.is_alive(), board.num_alive_neighbors_at(x, y)?);
+            Ok(if alive { old_cell.to_alive() } else { old_cell.to_dead() })
+        });
+    }
+
+    // Writes the next generation into the back buffer and swaps it in.
+    fn _step<F>(&mut self, next_cell_state_at: F) -> StepStats
+        where F: Fn(&Self, usize, usize) -> Result<T, LifeBoardError>
+    {
+        let mut back_cells = self._take_back_cells();
+        self._build_quadtree();
+        let stats = self._next_cells(&mut back_cells, next_cell_state_at);
+        self.quadtree = None;
+        self.back_cells = mem::replace(&mut self.cells, back_cells);
+        stats
+    }
+
+    // Steps on the rayon pool, letting work stealing balance chunks instead of fixed per-thread ranges.
+    #[cfg(feature = "rayon")]
+    pub fn simulate_rayon(&mut self) -> StepStats where T: Send + Sync {
+        use rayon::prelude::*;
+        self._apply_auto_expand();
+        let mut back_cells = self._take_back_cells();
+        self._build_quadtree();
+        let board = &*self;
+        let chunk_size = board.cells.len().div_ceil(rayon::current_num_threads() * 4).max(1);
+        let stats = back_cells.par_chunks_mut(chunk_size).enumerate().map(|(chunk_idx, chunk)| {
+            let mut stats = StepStats::default();
+            for (index, cell) in (chunk_idx * chunk_size..).zip(chunk.iter_mut()) {
+                *cell = board.next_cell_state_at(index / board.height, index % board.height).expect("Should always be valid indexes");
+                stats.record(board.cells[index].is_alive(), cell.is_alive());
+            }
+            stats
+        }).reduce(StepStats::default, |mut stats, chunk_stats| {
+            stats.merge(chunk_stats);
+            stats
+        });
+        self.quadtree = None;
+        self.back_cells = mem::replace(&mut self.cells, back_cells);
+        self._apply_auto_expand();
+        stats
+    }
+
+    // Counting neighbors through a quadtree skips the empty parts of large sparse boards. `threshold` is in cells.
+    pub fn with_quadtree_threshold(mut self, threshold: usize) -> BaseLifeBoard<T> {
+        self.quadtree_threshold = Some(threshold);
+        self
+    }
+
+    pub fn quadtree_threshold(&self) -> Option<usize> { self.quadtree_threshold }
+
+    fn _build_quadtree(&mut self) {
+        if self.quadtree_threshold.is_none_or(|threshold| self.cells.len() < threshold) {
+            return
+        }
+        let mut tree = QuadTree::new(self.width, self.height);
+        for (index, cell) in self.cells.iter().enumerate() {
+            if cell.is_alive() {
+                tree.insert(index / self.height, index % self.height);
             }
-            grid.push(col);
         }
+        self.quadtree = Some(tree);
+    }
+
+    // The quadtree sums a whole square at once, so it only serves square neighborhoods that don't wrap.
+    fn _quadtree_neighbors_at(&self, x: usize, y: usize) -> Option<u8> {
+        let tree = self.quadtree.as_ref()?;
+        if self.neighborhood == Neighborhood::VonNeumann || self.wrap_x || self.wrap_y {
+            return None
+        }
+        let radius = self.neighborhood.radius() as usize;
+        let x_range = x.saturating_sub(radius)..(x + radius + 1).min(self.width);
+        let y_range = y.saturating_sub(radius)..(y + radius + 1).min(self.height);
+        let neighbors = tree.count_in(x_range, y_range) - self.cells[self._index(x, y)].is_alive() as usize;
+        Some(neighbors as u8)
+    }
+
+    // The back buffer, sized to the board. It only needs to be filled again after the dimensions change.
+    fn _take_back_cells(&mut self) -> Vec<T> {
+        let mut back_cells = mem::take(&mut self.back_cells);
+        if back_cells.len() != self.cells.len() {
+            back_cells.clone_from(&self.cells);
+        }
+        back_cells
+    }
+
+    fn _next_cell_at_index(&self, index: usize) -> T {
+        self.next_cell_state_at(index / self.height, index % self.height).expect("Should always be valid indexes")
+    }
+
+    fn _next_cells<F>(&self, new_cells: &mut [T], next_cell_state_at: F) -> StepStats
+        where F: Fn(&Self, usize, usize) -> Result<T, LifeBoardError>
+    {
+        let mut stats = StepStats::default();
+        for (index, new_cell) in new_cells.iter_mut().enumerate() {
+            let (row_idx, col_idx) = (index / self.height, index % self.height);
+            *new_cell = next_cell_state_at(self, row_idx, col_idx).expect("Should always access a valid index");
+            stats.record(self.cells[index].is_alive(), new_cell.is_alive());
+        }
+        stats
+    }
+
+    pub fn simulate_expanding(&mut self, margin: usize) {
+        self._expand_to_margin(margin);
+        self.simulate();
+        self._expand_to_margin(margin);
+    }
+
+    pub fn auto_expand(&self) -> Option<usize> { self.auto_expand }
+
+    // With `Some(margin)`, `simulate` behaves like `simulate_expanding(margin)` so patterns never reach the edges.
+    pub fn set_auto_expand(&mut self, margin: Option<usize>) { self.auto_expand = margin; }
+
+    // Pads the board to the auto-expand margin, if one is set, and reports whether any cells moved.
+    pub(crate) fn _apply_auto_expand(&mut self) -> bool {
+        self.auto_expand.is_some_and(|margin| self._expand_to_margin(margin))
+    }
+
+    fn _expand_to_margin(&mut self, margin: usize) -> bool {
+        let (x_range, y_range) = match self.live_bounds() {
+            Some(bounds) => bounds,
+            None => return false,
+        };
+        let pad = |near_gap: usize, far_gap: usize| (
+            if near_gap < margin { margin } else { 0 },
+            if far_gap < margin { margin } else { 0 },
+        );
+        let (left, right) = pad(x_range.start, self.width - x_range.end);
+        let (top, bottom) = pad(y_range.start, self.height - y_range.end);
+        if left + right + top + bottom == 0 {
+            return false;
+        }
+        let dead_cell = self.cells[self._index(x_range.start, y_range.start)].to_dead();
+        self._pad(left, right, top, bottom, dead_cell);
+        true
+    }
 
-        BaseLifeBoard { grid, width, height }
+    fn _pad(&mut self, left: usize, right: usize, top: usize, bottom: usize, dead_cell: T) {
+        let (new_width, new_height) = (left + self.width + right, top + self.height + bottom);
+        let mut cells = Vec::with_capacity(new_width * new_height);
+        cells.resize(left * new_height, dead_cell.clone());
+        for col in self._columns() {
+            cells.resize(cells.len() + top, dead_cell.clone());
+            cells.extend_from_slice(col);
+            cells.resize(cells.len() + bottom, dead_cell.clone());
+        }
+        cells.resize(new_width * new_height, dead_cell);
+        self.cells = cells;
+        self.width = new_width;
+        self.height = new_height;
     }
 
     fn _is_cell_alive(&self, x: i64, y: i64) -> Option<bool> {
         self._cell_at(x, y).map(|cell| cell.is_alive())
     }
 
-    fn _cell_at(&self, x: i64, y: i64) -> Option<T> {
-        let (x, y) = match (x, y) {
-            (x, _) if x < 0 => return None,
-            (_, y) if y < 0 => return None,
-            _ => (x as usize, y as usize),
-        };
-        match self.grid.get(x) {
-            Some(row) => match row.get(y) {
-                Some(cell) => Some(cell.clone()),
-                None => None
-            },
-            None => None
+    // Maps a coordinate that may be off the board back onto it along each wrapping axis.
+    fn _wrapped(&self, x: i64, y: i64) -> (i64, i64) {
+        let x = if self.wrap_x { x.rem_euclid(self.width as i64) } else { x };
+        let y = if self.wrap_y { y.rem_euclid(self.height as i64) } else { y };
+        (x, y)
+    }
+
+    pub(crate) fn _set_cell(&mut self, x: usize, y: usize, cell: T) {
+        let index = self._index(x, y);
+        self.cells[index] = cell;
+    }
+
+    fn _cell_at(&self, x: i64, y: i64) -> Option<&T> {
+        match (usize::try_from(x), usize::try_from(y)) {
+            (Ok(x), Ok(y)) if x < self.width && y < self.height => Some(&self.cells[self._index(x, y)]),
+            _ => None,
         }
     }
 
-    fn into_vec_matrix(self) -> Vec<Vec<T>> { self.grid }
+    #[allow(dead_code)]
+    fn into_vec_matrix(self) -> Vec<Vec<T>> { self.to_vec_matrix() }
 
-    fn _board_fmt(&self, f: &mut Formatter<'_>, alive_cell: &str, dead_cell: &str, dbg: bool) -> fmt::Result {
-        for col_idx in 0..self.height() {
-            for row_idx in 0..self.width() {
-                let cell = self.cell_at(row_idx, col_idx).expect("Should always be valid indices");
-                let alive = if cell.is_alive() { alive_cell } else { dead_cell };
-                let cell_string = if dbg {
-                    format!("({alive}, {row_idx}, {col_idx}) ")
-                } else {
-                    format!("{} ", alive)
-                };
-                write!(f, "{}", cell_string)?;
-            }
-            let newline = if col_idx == self.width-1 { "" } else { "\n" };
-            write!(f, "{}", newline)?;
+    pub fn into_bool_matrix(self) -> Vec<Vec<bool>> { self.to_bool_matrix() }
+
+    // The outer vectors of `to_vec_matrix` borrowed in place, so there is one slice of `height` cells per x.
+    pub fn rows(&self) -> Rows<'_, T> { self._columns() }
+
+    // Converts every cell to another cell type, keeping the dimensions, neighborhood and wrapping.
+    pub fn map<U: LifeCell, F: Fn(&T) -> U>(&self, f: F) -> BaseLifeBoard<U> {
+        BaseLifeBoard {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+            neighborhood: self.neighborhood,
+            wrap_x: self.wrap_x,
+            wrap_y: self.wrap_y,
+            auto_expand: self.auto_expand,
+            back_cells: Vec::new(),
+            quadtree_threshold: self.quadtree_threshold,
+            quadtree: None,
+        }
+    }
+
+    // Visits every cell with its `(x, y)` coordinates, column by column.
+    pub fn for_each_mut<F: FnMut(usize, usize, &mut T)>(&mut self, mut f: F) {
+        let height = self.height;
+        for (index, cell) in self.cells.iter_mut().enumerate() {
+            f(index / height, index % height, cell);
         }
-        write!(f, "{}", "")
     }
-} impl <T: LifeCell<T>> LifeBoard<T> for BaseLifeBoard<T> {
+
+    pub fn to_flat(&self) -> Vec<bool> {
+        (0..self.height).flat_map(|y| (0..self.width).map(move |x| self.cells[self._index(x, y)].is_alive())).collect()
+    }
+
+    fn _board_fmt(&self, f: &mut Formatter<'_>, alive_cell: &str, dead_cell: &str, separator: &str, trailing_separator: bool, dbg: bool) -> fmt::Result {
+        life_interface::write_board(f, self.width, self.height, separator, trailing_separator, |f, row_idx, col_idx| {
+            let cell = self.cell_at(row_idx, col_idx).expect("Should always be valid indices");
+            let alive = if cell.is_alive() { alive_cell } else { dead_cell };
+            if dbg {
+                write!(f, "({alive}, {row_idx}, {col_idx})")
+            } else {
+                f.write_str(alive)
+            }
+        })
+    }
+} impl <T: LifeCell> LifeBoard<T> for BaseLifeBoard<T> {
     fn width(&self) -> usize { self.width }
     fn height(&self) -> usize { self.height }
 
     fn simulate(&mut self) {
-        let mut new_grid: Vec<Vec<T>> = Vec::with_capacity(self.width);
-        for row_idx in 0..self.width {
-            let mut new_col = Vec::with_capacity(self.height);
-            for col_idx in 0..self.height {
-                let new_cell = self.next_cell_state_at(row_idx, col_idx)
-                    .expect("Should always access a valid index");
-                new_col.push(new_cell);
-            }
-            new_grid.push(new_col);
-        }
-        self.grid = new_grid;
+        self.simulate_stats();
     }
 
-    fn simulate_n_steps(&mut self, n: usize) {
-        for _ in 0..n {
-            self.simulate();
-        }
+    fn simulate_stats(&mut self) -> StepStats {
+        self._apply_auto_expand();
+        let stats = self._step(|board, x, y| board.next_cell_state_at(x, y));
+        self._apply_auto_expand();
+        stats
     }
 
-    fn next_cell_state_at(&self, x: usize, y: usize) -> Option<T> {
-        let old_cell = match self.cell_at(x, y) {
-            Some(cell) => cell,
-            None => return None,
-        };
-        let new_cell = match self.num_alive_neighbors_at(x, y) {
+    fn next_cell_state_at(&self, x: usize, y: usize) -> Result<T, LifeBoardError> {
+        let old_cell = self.cell_at(x, y)?;
+        let new_cell = match self.num_alive_neighbors_at(x, y)? {
             0|1 if old_cell.is_alive() => old_cell.to_dead(),
             2|3 if old_cell.is_alive() => old_cell.to_alive(),
             4..=8 if old_cell.is_alive() => old_cell.to_dead(),
             3 if !old_cell.is_alive() => old_cell.to_alive(),
             _ => old_cell.to_dead(),
         };
-        Some(new_cell)
+        Ok(new_cell)
     }
 
-    fn cell_at(&self, x: usize, y: usize) -> Option<T> { self._cell_at(x as i64, y as i64) }
+    fn cell_at(&self, x: usize, y: usize) -> Result<&T, LifeBoardError> {
+        self._cell_at(x as i64, y as i64).ok_or_else(|| LifeBoardError::InvalidIndex(
+            format!("Cell ({x}, {y}) is outside of the {}x{} board.", self.width, self.height)
+        ))
+    }
 
-    fn num_alive_neighbors_at(&self, x: usize, y: usize) -> u8 {
+    fn num_alive_neighbors_at(&self, x: usize, y: usize) -> Result<u8, LifeBoardError> {
+        self.cell_at(x, y)?;
+        if let Some(neighbors) = self._quadtree_neighbors_at(x, y) {
+            return Ok(neighbors)
+        }
         let mut neighbors = 0u8;
-        for dx in 0..3 {
-            for dy in 0..3 {
-                if dx == 1 && dy == 1 {
+        let radius = self.neighborhood.radius();
+        for dx in -radius..=radius {
+            for dy in -radius..=radius {
+                if (dx == 0 && dy == 0) || !self.neighborhood.contains(dx, dy) {
                     continue
                 } else {
-                    let (x_test, y_test) = ((x as i64 - 1) + dx, (y as i64 - 1) + dy);
+                    let (x_test, y_test) = self._wrapped(x as i64 + dx, y as i64 + dy);
                     if let Some(is_alive) = self._is_cell_alive(x_test, y_test) {
                         if is_alive { neighbors += 1; }
                     }
                 }
             }
         }
-        neighbors
+        Ok(neighbors)
+    }
+
+    fn is_cell_alive(&self, x: usize, y: usize) -> Result<bool, LifeBoardError> { self.cell_at(x, y).map(|cell| cell.is_alive()) }
+
+    fn to_vec_matrix(&self) -> Vec<Vec<T>> { self._columns().map(<[T]>::to_vec).collect() }
+
+    fn fill_region(&mut self, x: usize, y: usize, w: usize, h: usize, alive: bool) -> Result<(), LifeBoardError> {
+        let (x_range, y_range) = life_interface::region_ranges(self.width, self.height, x, y, w, h)?;
+        let height = self.height;
+        for col in self.cells.chunks_exact_mut(height.max(1)).skip(x_range.start).take(x_range.len()) {
+            for cell in &mut col[y_range.clone()] {
+                *cell = if alive { cell.to_alive() } else { cell.to_dead() };
+            }
+        }
+        Ok(())
     }
 
-    fn is_cell_alive(&self, x: usize, y: usize) -> Option<bool> { self._is_cell_alive(x as i64, y as i64) }
+    fn count_alive(&self) -> usize {
+        self.cells.iter().filter(|cell| cell.is_alive()).count()
+    }
+
+    fn is_stable(&self) -> bool {
+        (0..self.width).all(|x|
+            (0..self.height).all(|y| {
+                let next_cell = self.next_cell_state_at(x, y).expect("Should always access a valid index");
+                next_cell.is_alive() == self.cells[self._index(x, y)].is_alive()
+            })
+        )
+    }
+} impl <T: LifeCell> BitOr for &BaseLifeBoard<T> {
+    type Output = Result<BaseLifeBoard<T>, LifeBoardError>;
+
+    fn bitor(self, other: Self) -> Self::Output { self.bitwise_or(other) }
+} impl <T: LifeCell> BitAnd for &BaseLifeBoard<T> {
+    type Output = Result<BaseLifeBoard<T>, LifeBoardError>;
+
+    fn bitand(self, other: Self) -> Self::Output { self.bitwise_and(other) }
+} impl <T: LifeCell> BitXor for &BaseLifeBoard<T> {
+    type Output = Result<BaseLifeBoard<T>, LifeBoardError>;
+
+    fn bitxor(self, other: Self) -> Self::Output { self.bitwise_xor(other) }
+} impl <T: LifeCell> Not for &BaseLifeBoard<T> {
+    type Output = BaseLifeBoard<T>;
+
+    fn not(self) -> Self::Output { self.bitwise_not() }
+} impl <T: LifeCell> Not for BaseLifeBoard<T> {
+    type Output = BaseLifeBoard<T>;
 
-    fn to_vec_matrix(&self) -> Vec<Vec<T>> { self.grid.clone() }
-} impl <T: LifeCell<T>> Display for BaseLifeBoard<T> {
+    fn not(self) -> Self::Output { self.bitwise_not() }
+} impl <T: LifeCell> Display for BaseLifeBoard<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        self._board_fmt(f, "*", " ", false)
+        self._board_fmt(f, "*", " ", " ", true, false)
     }
-} impl <T: LifeCell<T>> Debug for BaseLifeBoard<T> {
+} impl <T: LifeCell> Debug for BaseLifeBoard<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        self._board_fmt(f, "T", "F", true)
+        self._board_fmt(f, "T", "F", " ", true, true)
+    }
+} impl <T: LifeCell> Hash for BaseLifeBoard<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        life_interface::hash_board_state(self.width, self.height, |x, y| self.cells[self._index(x, y)].is_alive(), state);
+    }
+} impl <T: LifeCell> PartialEq for BaseLifeBoard<T> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.width != other.width || self.height != other.height {
+            return false;
+        }
+        self.cells == other.cells
+    }
+}
+
+// Unlike `chunks_exact`, still yields `width` empty columns for a board that is 0 cells tall.
+#[derive(Clone, Debug)]
+pub struct Rows<'a, T> {
+    cells: &'a [T],
+    height: usize,
+    x_range: Range<usize>,
+} impl <'a, T> Rows<'a, T> {
+    fn column(&self, x: usize) -> &'a [T] { &self.cells[x * self.height..(x + 1) * self.height] }
+} impl <'a, T> Iterator for Rows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> { self.x_range.next().map(|x| self.column(x)) }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { self.x_range.size_hint() }
+
+    fn nth(&mut self, n: usize) -> Option<&'a [T]> { self.x_range.nth(n).map(|x| self.column(x)) }
+} impl <T> DoubleEndedIterator for Rows<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> { self.x_range.next_back().map(|x| self.column(x)) }
+} impl <T> ExactSizeIterator for Rows<'_, T> {
+} impl <'a, T: LifeCell> IntoIterator for &'a BaseLifeBoard<T> {
+    type Item = &'a [T];
+    type IntoIter = Rows<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter { self.rows() }
+} impl <T: LifeCell> Index<(usize, usize)> for BaseLifeBoard<T> {
+    type Output = T;
+
+    // Panics like `cell_at` would fail for a cell outside of the board.
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.cell_at(x, y).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedLifeBoard<T> {
+    grid: Vec<Vec<T>>,
+    #[serde(default)]
+    neighborhood: Neighborhood,
+    #[serde(default)]
+    wrap_x: bool,
+    #[serde(default)]
+    wrap_y: bool,
+    #[serde(default)]
+    auto_expand: Option<usize>,
+}
+
+#[cfg(feature = "serde")]
+impl <T: LifeCell> TryFrom<SerializedLifeBoard<T>> for BaseLifeBoard<T> {
+    type Error = String;
+
+    fn try_from(board: SerializedLifeBoard<T>) -> Result<Self, Self::Error> {
+        let mut life_board = BaseLifeBoard::_from_grid(board.grid).map_err(|error| format!("{error:?}"))?;
+        life_board.set_neighborhood(board.neighborhood).map_err(|error| format!("{error:?}"))?;
+        life_board.set_wrap(board.wrap_x, board.wrap_y);
+        life_board.auto_expand = board.auto_expand;
+        Ok(life_board)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl <T: LifeCell> From<BaseLifeBoard<T>> for SerializedLifeBoard<T> {
+    fn from(board: BaseLifeBoard<T>) -> Self {
+        SerializedLifeBoard {
+            grid: board.to_vec_matrix(), neighborhood: board.neighborhood, wrap_x: board.wrap_x, wrap_y: board.wrap_y,
+            auto_expand: board.auto_expand,
+        }
     }
 }
 
 #[derive(PartialEq, Clone)]
-pub struct ParallelLifeBoard<T: LifeCell<T> + Sync + Send> {
-    board: Arc<BaseLifeBoard<T>>,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(
+    try_from = "SerializedParallelLifeBoard<T>",
+    into = "SerializedParallelLifeBoard<T>",
+    bound(serialize = "T: serde::Serialize", deserialize = "T: serde::Deserialize<'de>")
+))]
+pub struct ParallelLifeBoard<T: LifeCell + Sync + Send> {
+    board: BaseLifeBoard<T>,
     n_threads: usize,
     thread_row_ranges: Vec<Range<usize>>,
-} impl <T: LifeCell<T> + Sync + Send> ParallelLifeBoard<T> {
+    split_axis: SplitAxis,
+} impl <T: LifeCell + Sync + Send> ParallelLifeBoard<T> {
     fn row_ranges(width: usize, nthreads: usize) -> Vec<Range<usize>> {
-        let slice_size = width / nthreads;
+        let (slice_size, remainder) = (width / nthreads, width % nthreads);
         let mut cur_left_col = 0;
-        (1..=nthreads).map(|thread_idx| {
-            if thread_idx == nthreads {
-                cur_left_col..width
-            } else {
-                let range = cur_left_col..cur_left_col + slice_size;
-                cur_left_col += slice_size;
-                range
-            }
+        (0..nthreads).map(|thread_idx| {
+            // The first `remainder` threads each take one of the leftover columns.
+            let size = slice_size + (thread_idx < remainder) as usize;
+            let range = cur_left_col..cur_left_col + size;
+            cur_left_col += size;
+            range
         }).collect()
     }
 
@@ -222,147 +850,305 @@ pub struct ParallelLifeBoard<T: LifeCell<T> + Sync + Send> {
             B: IntoIterator<Item=bool>
     {
         let board = BaseLifeBoard::from_bool_matrix(collection, gen);
-        board.map(|board|
-            ParallelLifeBoard {
-                thread_row_ranges: ParallelLifeBoard::<T>::row_ranges(board.width, n_threads as usize),
-                n_threads: n_threads as usize,
-                board: Arc::new(board),
-            }
-        )
+        board.map(|board| ParallelLifeBoard::from_board(board, n_threads))
     }
 
     pub fn from_board(board: BaseLifeBoard<T>, n_threads: u8) -> ParallelLifeBoard<T> {
         ParallelLifeBoard {
             thread_row_ranges: ParallelLifeBoard::<T>::row_ranges(board.width, n_threads as usize),
+            split_axis: SplitAxis::Rows,
             n_threads: n_threads as usize,
-            board: Arc::new(board),
+            board,
         }
     }
 
     fn _from_grid(grid: Vec<Vec<T>>, n_threads: u8) -> Result<ParallelLifeBoard<T>, LifeBoardError> {
         let board = BaseLifeBoard::_from_grid(grid);
-        board.map(|board|
-            ParallelLifeBoard {
-                thread_row_ranges: ParallelLifeBoard::<T>::row_ranges(board.width, n_threads as usize),
-                n_threads: n_threads as usize,
-                board: Arc::new(board),
-            }
-        )
+        board.map(|board| ParallelLifeBoard::from_board(board, n_threads))
     }
 
     pub fn gen(width: usize, height: usize, n_threads: u8, gen: fn() ->T) -> ParallelLifeBoard<T> {
-        let board = BaseLifeBoard::gen(width, height, gen);
-        ParallelLifeBoard {
-            thread_row_ranges: ParallelLifeBoard::<T>::row_ranges(width, n_threads as usize),
-            n_threads: n_threads as usize,
-            board: Arc::new(board),
-        }
+        ParallelLifeBoard::from_board(BaseLifeBoard::gen(width, height, gen), n_threads)
     }
 
-    fn _is_cell_alive(&self, x: i64, y: i64) -> Option<bool> {
-        self.board._cell_at(x, y).map(|cell| cell.is_alive())
+    /// Like `gen`, but picks the thread count from `std::thread::available_parallelism`.
+    pub fn gen_auto(width: usize, height: usize, gen: fn() -> T) -> ParallelLifeBoard<T> {
+        ParallelLifeBoard::gen(width, height, ParallelLifeBoard::<T>::_auto_thread_count(width), gen)
     }
 
-    fn _cell_at(&self, x: i64, y: i64) -> Option<T> {
-        self.board._cell_at(x, y)
+    /// Like `from_board`, but picks the thread count from `std::thread::available_parallelism`.
+    pub fn from_board_auto(board: BaseLifeBoard<T>) -> ParallelLifeBoard<T> {
+        let n_threads = ParallelLifeBoard::<T>::_auto_thread_count(board.width);
+        ParallelLifeBoard::from_board(board, n_threads)
     }
-} impl <T: LifeCell<T> + Sync + Send + 'static> LifeBoard<T> for ParallelLifeBoard<T> {
-    fn width(&self) -> usize { self.board.width }
 
-    fn height(&self) -> usize { self.board.height }
+    fn _auto_thread_count(width: usize) -> u8 {
+        let available = thread::available_parallelism().map_or(1, |n| n.get());
+        available.min(width).clamp(1, u8::MAX as usize) as u8
+    }
 
-    fn simulate(&mut self) {
-        let (tx, rx) = mpsc::channel::<(Vec<Vec<T>>, usize)>();
-        let mut thread_handles = Vec::with_capacity(self.n_threads);
-        for thread_idx in 0..self.n_threads {
-            let row_range = self.thread_row_ranges[thread_idx].clone();
-            let board = self.board.clone();
-            let tx = tx.clone();
-            let thread_handle = thread::spawn(move || {
-                let mut board_slice: Vec<Vec<T>> = Vec::with_capacity(row_range.end);
-                for row_idx in row_range {
-                    let mut col = Vec::with_capacity(board.height);
-                    for col_idx in 0..board.height {
-                        col.push(
-                            board.next_cell_state_at(row_idx, col_idx)
-                                .expect("Should always be valid indexes")
-                        )
-                    }
-                    board_slice.push(col);
-                }
-                tx.send((board_slice, thread_idx)).unwrap();
-            });
-            thread_handles.push(thread_handle);
-        }
-        let mut new_gird: Vec<Vec<T>> = (0..self.board.width).map(|_| Vec::new()).collect();
-        for handle in thread_handles {
-            let _ = handle.join().expect("Threads should join correctly.");
-        }
-        for _ in 0..self.n_threads {
-            let (board_slice, thread_idx) = rx.recv().expect("Should receive values correctly.");
-            let row_range = self.thread_row_ranges[thread_idx].clone();
-            for (board_col, row_idx) in board_slice.into_iter().zip(row_range) {
-                new_gird[row_idx] = board_col;
-            }
+    pub fn n_threads(&self) -> u8 { self.n_threads as u8 }
+
+    pub fn set_thread_count(&mut self, n_threads: u8) -> Result<(), LifeBoardError> {
+        if n_threads == 0 {
+            return Err(LifeBoardError::InvalidBoard(String::from("Board must use at least one thread.")));
         }
-        self.board = Arc::new(
-            BaseLifeBoard {
-                grid: new_gird,
-                width: self.board.width,
-                height: self.board.height
-            });
+        self.n_threads = n_threads as usize;
+        self._update_thread_ranges();
+        Ok(())
     }
 
-    fn simulate_n_steps(&mut self, steps: usize) {
-        for _ in 0..steps {
-            self.simulate()
-        }
+    // The x ranges (or y ranges when splitting by columns) each thread steps.
+    pub fn thread_ranges(&self) -> &[Range<usize>] { &self.thread_row_ranges }
+
+    pub fn into_board(self) -> BaseLifeBoard<T> { self.board }
+
+    pub fn neighborhood(&self) -> Neighborhood { self.board.neighborhood }
+
+    pub fn set_neighborhood(&mut self, neighborhood: Neighborhood) -> Result<(), LifeBoardError> {
+        self.board.set_neighborhood(neighborhood)
     }
 
-    fn next_cell_state_at(&self, x: usize, y: usize) -> Option<T> { self.board.next_cell_state_at(x, y) }
+    pub fn wrap_x(&self) -> bool { self.board.wrap_x }
 
-    fn cell_at(&self, x: usize, y: usize) -> Option<T> { self.board.cell_at(x, y) }
+    pub fn wrap_y(&self) -> bool { self.board.wrap_y }
 
-    fn num_alive_neighbors_at(&self, x: usize, y: usize) -> u8 { self.board.num_alive_neighbors_at(x, y) }
+    pub fn set_wrap(&mut self, wrap_x: bool, wrap_y: bool) { self.board.set_wrap(wrap_x, wrap_y); }
 
-    fn is_cell_alive(&self, x: usize, y: usize) -> Option<bool> { self.board.is_cell_alive(x, y) }
+    pub fn auto_expand(&self) -> Option<usize> { self.board.auto_expand }
 
-    fn to_vec_matrix(&self) -> Vec<Vec<T>> { self.board.grid.clone() }
-} impl <T: LifeCell<T> + Sync + Send> Debug for ParallelLifeBoard<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        Debug::fmt(&self.board, f)
+    pub fn set_auto_expand(&mut self, margin: Option<usize>) { self.board.set_auto_expand(margin); }
+
+    pub fn split_axis(&self) -> SplitAxis { self.split_axis }
+
+    pub fn set_split_axis(&mut self, split_axis: SplitAxis) {
+        self.split_axis = split_axis;
+        self._update_thread_ranges();
     }
-} impl <T: LifeCell<T> + Sync + Send> Display for ParallelLifeBoard<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        Display::fmt(&self.board, f)
+
+    fn _update_thread_ranges(&mut self) {
+        let axis_len = match self.split_axis {
+            SplitAxis::Rows => self.board.width,
+            SplitAxis::Columns => self.board.height,
+        };
+        self.thread_row_ranges = ParallelLifeBoard::<T>::row_ranges(axis_len, self.n_threads);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::life_implementation::{BaseLifeBoard, Cell, ParallelLifeBoard};
-    use crate::life_interface::{LifeBoard, LifeBoardError};
+    pub fn resize(&mut self, new_width: usize, new_height: usize, fill: bool) -> Result<(), LifeBoardError> {
+        self.board.resize(new_width, new_height, fill)?;
+        self._update_thread_ranges();
+        Ok(())
+    }
 
-    fn assert_contains(actual: String, expected: &str) {
-        assert!(
-            actual.contains(expected),
-            "Expected \"{actual}\" to contain \"{expected}\"")
+    pub fn simulate_expanding(&mut self, margin: usize) {
+        self._expand_to_margin(margin);
+        self.simulate();
+        self._expand_to_margin(margin);
     }
 
-    fn get_3x3_board(array: [[bool;3];3]) -> BaseLifeBoard<Cell> {
-        BaseLifeBoard::from_bool_matrix(array, Cell::from_bool).unwrap()
+    fn _expand_to_margin(&mut self, margin: usize) {
+        if self.board._expand_to_margin(margin) {
+            self._update_thread_ranges();
+        }
     }
 
-    #[test]
-    fn test_exception_life_board_from_matrix_invalid_row() {
-        let grid: Vec<Vec<Cell>> = Vec::new();
-        match BaseLifeBoard::from_cell_matrix(grid) {
-            Ok(_) => panic!("Board should be invalid."),
-            Err(LifeBoardError::InvalidBoard(error)) => {
-                assert_contains(error, "at least one cell wide");
-            },
-            Err(error) => panic!("Unexpected LifeBoardError {error:?}"),
-        };
+    pub fn try_simulate(&mut self) -> Result<(), LifeBoardError> { self.try_simulate_stats().map(|_| ()) }
+
+    // Like `simulate_stats`, but a panicking worker becomes a `WorkerPanic` error and the board keeps its current generation.
+    pub fn try_simulate_stats(&mut self) -> Result<StepStats, LifeBoardError> {
+        if let Some(margin) = self.board.auto_expand {
+            self._expand_to_margin(margin);
+        }
+        let mut next_cells = self.board._take_back_cells();
+        self.board._build_quadtree();
+        let thread_stats = self._next_cells(&mut next_cells);
+        self.board.quadtree = None;
+        let mut stats = StepStats::default();
+        for (thread_idx, slice_stats) in thread_stats.into_iter().enumerate() {
+            let slice_stats = slice_stats.map_err(|payload| LifeBoardError::WorkerPanic(
+                format!("Worker {thread_idx} panicked: {}", panic_message(payload.as_ref()))
+            ))?;
+            stats.merge(slice_stats);
+        }
+        self.board.back_cells = mem::replace(&mut self.board.cells, next_cells);
+        if let Some(margin) = self.board.auto_expand {
+            self._expand_to_margin(margin);
+        }
+        Ok(stats)
+    }
+
+    // Splits `next_cells` into the disjoint runs each thread steps, paired with the index of their first cell. A range
+    // of x is one contiguous run, while a range of y is a run in every column.
+    fn _thread_segments<'a>(&self, next_cells: &'a mut [T]) -> Vec<Vec<(usize, &'a mut [T])>> {
+        let height = self.board.height;
+        let mut thread_segments: Vec<Vec<(usize, &mut [T])>> = self.thread_row_ranges.iter().map(|_| Vec::new()).collect();
+        match self.split_axis {
+            SplitAxis::Rows => {
+                let mut rest = next_cells;
+                for (segments, range) in thread_segments.iter_mut().zip(&self.thread_row_ranges) {
+                    let (segment, tail) = rest.split_at_mut(range.len() * height);
+                    segments.push((range.start * height, segment));
+                    rest = tail;
+                }
+            },
+            // A board without height has no cells, and so no segments, but `chunks_exact_mut` can't take a size of 0.
+            SplitAxis::Columns => for (x, col) in next_cells.chunks_exact_mut(height.max(1)).enumerate() {
+                let mut rest = col;
+                for (segments, range) in thread_segments.iter_mut().zip(&self.thread_row_ranges) {
+                    let (segment, tail) = rest.split_at_mut(range.len());
+                    segments.push((x * height + range.start, segment));
+                    rest = tail;
+                }
+            },
+        }
+        thread_segments
+    }
+
+    // Every thread writes its own segments of `next_cells` in place, borrowing the board for the length of the step.
+    fn _next_cells(&self, next_cells: &mut [T]) -> Vec<thread::Result<StepStats>> {
+        let board = &self.board;
+        let next_segments = move |segments: Vec<(usize, &mut [T])>| {
+            let mut stats = StepStats::default();
+            for (start, segment) in segments {
+                for (index, cell) in (start..).zip(segment.iter_mut()) {
+                    *cell = board._next_cell_at_index(index);
+                    stats.record(board.cells[index].is_alive(), cell.is_alive());
+                }
+            }
+            stats
+        };
+        let thread_segments = self._thread_segments(next_cells);
+        #[cfg(feature = "parallel")]
+        let thread_stats = thread::scope(|scope| {
+            let thread_handles: Vec<_> = thread_segments.into_iter()
+                .map(|segments| scope.spawn(move || next_segments(segments)))
+                .collect();
+            thread_handles.into_iter().map(|handle| handle.join()).collect()
+        });
+        // Without threads (e.g. on wasm32) the same segments are simply evaluated one after another.
+        #[cfg(not(feature = "parallel"))]
+        let thread_stats = thread_segments.into_iter()
+            .map(|segments| panic::catch_unwind(AssertUnwindSafe(|| next_segments(segments))))
+            .collect();
+        thread_stats
+    }
+
+    fn _is_cell_alive(&self, x: i64, y: i64) -> Option<bool> {
+        self.board._cell_at(x, y).map(|cell| cell.is_alive())
+    }
+
+    fn _cell_at(&self, x: i64, y: i64) -> Option<&T> {
+        self.board._cell_at(x, y)
+    }
+} impl <T: LifeCell + Sync + Send> LifeBoard<T> for ParallelLifeBoard<T> {
+    fn width(&self) -> usize { self.board.width }
+
+    fn height(&self) -> usize { self.board.height }
+
+    fn simulate(&mut self) {
+        self.simulate_stats();
+    }
+
+    fn simulate_stats(&mut self) -> StepStats {
+        self.try_simulate_stats().unwrap_or_else(|error| panic!("{error:?}"))
+    }
+
+    fn next_cell_state_at(&self, x: usize, y: usize) -> Result<T, LifeBoardError> { self.board.next_cell_state_at(x, y) }
+
+    fn cell_at(&self, x: usize, y: usize) -> Result<&T, LifeBoardError> { self.board.cell_at(x, y) }
+
+    fn num_alive_neighbors_at(&self, x: usize, y: usize) -> Result<u8, LifeBoardError> { self.board.num_alive_neighbors_at(x, y) }
+
+    fn is_cell_alive(&self, x: usize, y: usize) -> Result<bool, LifeBoardError> { self.board.is_cell_alive(x, y) }
+
+    fn to_vec_matrix(&self) -> Vec<Vec<T>> { self.board.to_vec_matrix() }
+
+    fn fill_region(&mut self, x: usize, y: usize, w: usize, h: usize, alive: bool) -> Result<(), LifeBoardError> {
+        self.board.fill_region(x, y, w, h, alive)
+    }
+
+    fn count_alive(&self) -> usize { self.board.count_alive() }
+
+    fn is_stable(&self) -> bool { self.board.is_stable() }
+} impl <T: LifeCell + Sync + Send> Debug for ParallelLifeBoard<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.board, f)
+    }
+} impl <T: LifeCell + Sync + Send> Display for ParallelLifeBoard<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.board, f)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::Deserialize<'de>"))]
+struct SerializedParallelLifeBoard<T: LifeCell> {
+    board: BaseLifeBoard<T>,
+    n_threads: u8,
+    #[serde(default)]
+    split_axis: SplitAxis,
+}
+
+#[cfg(feature = "serde")]
+impl <T: LifeCell + Sync + Send> TryFrom<SerializedParallelLifeBoard<T>> for ParallelLifeBoard<T> {
+    type Error = String;
+
+    fn try_from(board: SerializedParallelLifeBoard<T>) -> Result<Self, Self::Error> {
+        match board.n_threads {
+            0 => Err(String::from("ParallelLifeBoard must use at least one thread.")),
+            n_threads => {
+                let mut parallel_board = ParallelLifeBoard::from_board(board.board, n_threads);
+                parallel_board.set_split_axis(board.split_axis);
+                Ok(parallel_board)
+            },
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl <T: LifeCell + Sync + Send> From<ParallelLifeBoard<T>> for SerializedParallelLifeBoard<T> {
+    fn from(board: ParallelLifeBoard<T>) -> Self {
+        SerializedParallelLifeBoard {
+            n_threads: board.n_threads as u8,
+            split_axis: board.split_axis,
+            board: board.board,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::convert;
+    use std::hash::{Hash, Hasher};
+    use std::ops::Range;
+    use std::sync::mpsc;
+    use crate::life_implementation::{BaseLifeBoard, BoolOp, Cell, Neighborhood, ParallelLifeBoard, SplitAxis};
+    use crate::life_interface::{CellEvent, LifeBoard, LifeBoardError, LifeCell, Point, PopulationStatistics, StepStats};
+    use crate::patterns;
+    use crate::test_fixtures::{get_7x7_board_1st_gen, get_7x7_end_board_10th_gen, get_7x7_start_board_0th_gen};
+
+    fn assert_contains(actual: String, expected: &str) {
+        assert!(
+            actual.contains(expected),
+            "Expected \"{actual}\" to contain \"{expected}\"")
+    }
+
+    fn get_3x3_board(array: [[bool;3];3]) -> BaseLifeBoard<Cell> {
+        BaseLifeBoard::from_bool_matrix(array, Cell::from_bool).unwrap()
+    }
+
+    #[test]
+    fn test_exception_life_board_from_matrix_invalid_row() {
+        let grid: Vec<Vec<Cell>> = Vec::new();
+        match BaseLifeBoard::from_cell_matrix(grid) {
+            Ok(_) => panic!("Board should be invalid."),
+            Err(LifeBoardError::InvalidBoard(error)) => {
+                assert_contains(error, "at least one cell wide");
+            },
+            Err(error) => panic!("Unexpected LifeBoardError {error:?}"),
+        };
     }
 
     #[test]
@@ -409,8 +1195,8 @@ mod tests {
     fn test_equivalence_life_board_is_cell_alive_false() {
         let board = BaseLifeBoard::from_bool_matrix([[false]], Cell::from_bool).unwrap();
         match board.is_cell_alive(0, 0) {
-            Some(alive) => assert!(!alive, "The cell should be dead."),
-            None => panic!("Cell should be valid"),
+            Ok(alive) => assert!(!alive, "The cell should be dead."),
+            Err(error) => panic!("Cell should be valid: {error:?}"),
         }
     }
 
@@ -418,33 +1204,301 @@ mod tests {
     fn test_equivalence_life_board_is_cell_alive_true() {
         let board = BaseLifeBoard::from_bool_matrix([[true]], Cell::from_bool).unwrap();
         match board.is_cell_alive(0, 0) {
-            Some(alive) => assert!(alive, "The cell should be alive."),
-            None => panic!("Cell should be valid"),
+            Ok(alive) => assert!(alive, "The cell should be alive."),
+            Err(error) => panic!("Cell should be valid: {error:?}"),
         }
     }
 
     #[test]
     fn test_exception_life_board_is_cell_alive_invalid_y() {
         let board = BaseLifeBoard::from_bool_matrix([[true]], Cell::from_bool).unwrap();
-        if let Some(_) = board.is_cell_alive(0, 1) {
-            panic!("Cell should be invalid")
+        match board.is_cell_alive(0, 1) {
+            Err(LifeBoardError::InvalidIndex(error)) => assert!(error.contains("(0, 1)"), "{error}"),
+            result => panic!("Expected an InvalidIndex error but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_equivalence_life_board_flat_round_trip() {
+        let cells = [false, true, false, false, false, true, true, true, true, false, false, false];
+        let board = BaseLifeBoard::from_flat(&cells, 4, Cell::from_bool).unwrap();
+        assert_eq!((4, 3), (board.width(), board.height()));
+        assert_eq!((Ok(true), Ok(false)), (board.is_cell_alive(1, 0), board.is_cell_alive(0, 1)));
+        assert_eq!(cells.to_vec(), board.to_flat());
+    }
+
+    #[test]
+    fn test_exception_life_board_from_flat_ragged() {
+        for (cells, width) in [(&[true; 5][..], 2), (&[true; 4][..], 0), (&[][..], 3)] {
+            match BaseLifeBoard::from_flat(cells, width, Cell::from_bool) {
+                Err(LifeBoardError::InvalidBoard(_)) => {},
+                result => panic!("Expected an InvalidBoard error for {} cells of width {width} but found {result:?}", cells.len()),
+            }
+        }
+    }
+
+    // Counts how many generations a cell has survived, which only works if boards build cells through `to_alive`.
+    #[derive(PartialEq, Clone, Debug)]
+    struct AgeCell(Option<u32>);
+
+    impl LifeCell for AgeCell {
+        fn is_alive(&self) -> bool { self.0.is_some() }
+        fn to_alive(&self) -> AgeCell { AgeCell(Some(self.0.map_or(0, |age| age + 1))) }
+        fn to_dead(&self) -> AgeCell { AgeCell(None) }
+    }
+
+    #[test]
+    fn test_equivalence_custom_cell_ages_through_to_alive() {
+        let init = |alive: bool| AgeCell(alive.then_some(0));
+        let mut board = BaseLifeBoard::from_rle("x = 3, y = 3\n$3o!", init).unwrap();
+        board.simulate_n_steps(2);
+        assert_eq!((&AgeCell(Some(2)), &AgeCell(Some(0)), &AgeCell(None)), (
+            board.cell_at(1, 1).unwrap(), board.cell_at(0, 1).unwrap(), board.cell_at(1, 0).unwrap()
+        ));
+    }
+
+    // Panics as soon as any cell is born or survives, standing in for a buggy cell type inside a worker thread.
+    #[derive(PartialEq, Clone, Debug)]
+    struct FragileCell(bool);
+
+    impl LifeCell for FragileCell {
+        fn is_alive(&self) -> bool { self.0 }
+        fn to_alive(&self) -> FragileCell { panic!("fragile cell cannot live") }
+        fn to_dead(&self) -> FragileCell { FragileCell(false) }
+    }
+
+    #[test]
+    fn test_exception_parallel_worker_panic_is_an_error() {
+        let board = BaseLifeBoard::from_rle("x = 3, y = 3\n$3o!", FragileCell).unwrap();
+        let mut parallel_board = ParallelLifeBoard::from_board(board.clone(), 3);
+        match parallel_board.try_simulate() {
+            Err(LifeBoardError::WorkerPanic(error)) => assert!(error.contains("fragile cell cannot live"), "{error}"),
+            result => panic!("Expected a WorkerPanic error but found {result:?}"),
+        }
+        assert_eq!(board, parallel_board.into_board());
+    }
+
+    #[test]
+    fn test_equivalence_parallel_board_simulates_again_after_panic() {
+        let board = BaseLifeBoard::from_rle("x = 3, y = 3\n$3o!", FragileCell).unwrap();
+        let mut parallel_board = ParallelLifeBoard::from_board(board, 3);
+        assert!(parallel_board.try_simulate().is_err());
+        parallel_board.clear();
+        assert_eq!(Ok(()), parallel_board.try_simulate());
+        assert_eq!(0, parallel_board.count_alive());
+    }
+
+    #[test]
+    fn test_equivalence_parallel_board_matches_base_after_thread_count_change_and_clone() {
+        let mut base_board = BaseLifeBoard::gen_seeded(20, 20, 0.4, 2, Cell::from_bool);
+        let mut parallel_board = ParallelLifeBoard::from_board(base_board.clone(), 4);
+        base_board.simulate_n_steps(3);
+        parallel_board.simulate_n_steps(3);
+        parallel_board.set_thread_count(2).unwrap();
+        let mut cloned_board = parallel_board.clone();
+        base_board.simulate_n_steps(3);
+        parallel_board.simulate_n_steps(3);
+        cloned_board.simulate_n_steps(3);
+        assert_eq!(parallel_board, cloned_board);
+        assert_boards_eq(base_board, parallel_board.into_board());
+    }
+
+    #[test]
+    fn test_equivalence_map_bool_board_to_cell_board() {
+        let mut bool_board: BaseLifeBoard<bool> = BaseLifeBoard::from_rle("x = 4, y = 3\n$b3o!", convert::identity).unwrap();
+        bool_board.set_neighborhood(Neighborhood::VonNeumann).unwrap();
+        bool_board.set_wrap(true, false);
+        let cell_board = bool_board.map(|&alive| Cell::from_bool(alive));
+        assert_eq!(BaseLifeBoard::from_rle("x = 4, y = 3\n$b3o!", Cell::from_bool).unwrap(), cell_board);
+        assert_eq!((Neighborhood::VonNeumann, true, false), (cell_board.neighborhood(), cell_board.wrap_x(), cell_board.wrap_y()));
+        assert_eq!(bool_board, cell_board.map(LifeCell::is_alive));
+    }
+
+    #[test]
+    fn test_equivalence_fmt_with_custom_symbols() {
+        let expected = ".|.|.|.|.\n.|.|#|.|.\n.|.|#|.|.\n.|.|#|.|.\n.|.|.|.|.";
+        assert_eq!(expected, get_blinker_board().fmt_with("#", ".", "|").to_string());
+        let parallel_board = ParallelLifeBoard::from_board(get_blinker_board(), 2);
+        assert_eq!(expected, parallel_board.fmt_with("#", ".", "|").to_string());
+        let board = BaseLifeBoard::from_rle("x = 3, y = 2\nbo$o!", Cell::from_bool).unwrap();
+        assert_eq!("_O_\nO__", board.fmt_with("O", "_", "").to_string());
+    }
+
+    #[test]
+    fn test_equivalence_display_and_debug_follow_every_cell_with_a_space() {
+        let board = BaseLifeBoard::from_rle("x = 3, y = 2\nbo$o!", Cell::from_bool).unwrap();
+        assert_eq!("  *   \n*     ", board.to_string());
+        assert_eq!("(F, 0, 0) (T, 1, 0) (F, 2, 0) \n(T, 0, 1) (F, 1, 1) (F, 2, 1) ", format!("{board:?}"));
+    }
+
+    #[test]
+    fn test_equivalence_bool_matrix_round_trip() {
+        let board = get_7x7_start_board_0th_gen();
+        let matrix = board.to_bool_matrix();
+        assert_eq!(vec![false, true, false, true, false, false, false], matrix[0]);
+        assert_eq!(board, BaseLifeBoard::from_bool_matrix(matrix.clone(), Cell::from_bool).unwrap());
+        assert_eq!(matrix, ParallelLifeBoard::from_board(board.clone(), 2).to_bool_matrix());
+        assert_eq!(matrix, board.into_bool_matrix());
+    }
+
+    #[test]
+    fn test_equivalence_for_each_mut_diagonal() {
+        let mut board = BaseLifeBoard::gen(4, 4, || Cell::from_bool(false));
+        board.for_each_mut(|x, y, cell| if x == y { *cell = cell.to_alive() });
+        assert_eq!(BaseLifeBoard::from_rle("x = 4, y = 4\no$bo$2bo$3bo!", Cell::from_bool).unwrap(), board);
+    }
+
+    #[test]
+    fn test_equivalence_region_center_of_5x5_board() {
+        let board = BaseLifeBoard::from_rle("x = 5, y = 5\no3bo$bo$2bo$3b2o$o!", Cell::from_bool).unwrap();
+        let expected_board = BaseLifeBoard::from_rle("x = 3, y = 3\no$bo$2bo!", Cell::from_bool).unwrap();
+        assert_eq!(expected_board, board.region(1, 1, 3, 3).unwrap());
+    }
+
+    #[test]
+    fn test_exception_region_out_of_bounds() {
+        let board = BaseLifeBoard::gen(5, 5, Cell::gen);
+        match board.region(3, 1, 3, 3) {
+            Err(LifeBoardError::InvalidIndex(error)) => assert!(error.contains("outside of the 5x5 board"), "{error}"),
+            result => panic!("Expected an invalid index error but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_boundary_region_empty() {
+        let board = BaseLifeBoard::gen(5, 5, Cell::gen);
+        match board.region(5, 0, 0, 5) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("at least one cell"), "{error}"),
+            result => panic!("Expected an invalid board error but found {result:?}"),
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_equivalence_simulate_rayon_matches_simulate() {
+        let mut board = BaseLifeBoard::gen_seeded(37, 23, 0.4, 9, Cell::from_bool);
+        let mut rayon_board = board.clone();
+        for _ in 0..10 {
+            assert_eq!(board.simulate_stats(), rayon_board.simulate_rayon());
+        }
+        assert_boards_eq(board, rayon_board);
+    }
+
+    #[test]
+    fn test_equivalence_quadtree_threshold_simulate_7x7_board_10_steps() {
+        let mut board = get_7x7_start_board_0th_gen().with_quadtree_threshold(0);
+        board.simulate_n_steps(10);
+        assert_boards_eq(get_7x7_end_board_10th_gen(), board);
+    }
+
+    #[test]
+    fn test_equivalence_quadtree_matches_flat_neighbor_counts() {
+        let board = BaseLifeBoard::gen_seeded(31, 19, 0.3, 5, Cell::from_bool);
+        for neighborhood in [Neighborhood::Moore, Neighborhood::VonNeumann, Neighborhood::MooreRadius(3)] {
+            let mut flat_board = board.clone();
+            flat_board.set_neighborhood(neighborhood).unwrap();
+            let mut quadtree_board = flat_board.clone().with_quadtree_threshold(1);
+            let mut parallel_board = ParallelLifeBoard::from_board(quadtree_board.clone(), 3);
+            for _ in 0..5 {
+                assert_eq!(flat_board.simulate_stats(), quadtree_board.simulate_stats(), "{neighborhood:?}");
+                parallel_board.simulate();
+            }
+            assert_eq!(flat_board, quadtree_board);
+            assert_boards_eq(flat_board, parallel_board.into_board());
+        }
+    }
+
+    #[test]
+    fn test_equivalence_parallel_bool_board_matches_cell_board() {
+        let mut cell_board = get_7x7_start_board_0th_gen();
+        let mut bool_board = ParallelLifeBoard::from_board(cell_board.map(LifeCell::is_alive), 3);
+        for _ in 0..10 {
+            cell_board.simulate();
+            bool_board.simulate();
+        }
+        assert_eq!(cell_board.map(LifeCell::is_alive).to_vec_matrix(), bool_board.to_vec_matrix());
+    }
+
+    #[test]
+    fn test_equivalence_bool_cell_board_blinker() {
+        let mut board: BaseLifeBoard<bool> = BaseLifeBoard::from_rle("x = 3, y = 3\n$3o!", convert::identity).unwrap();
+        board.simulate();
+        assert_eq!(BaseLifeBoard::from_rle("x = 3, y = 3\nbo$bo$bo!", convert::identity).unwrap(), board);
+        assert_eq!((Ok(&true), Ok(&false)), (board.cell_at(1, 0), board.cell_at(0, 1)));
+        let mut parallel_board = ParallelLifeBoard::from_board(board.clone(), 2);
+        parallel_board.simulate_n_steps(2);
+        assert_eq!(board, parallel_board.into_board());
+    }
+
+    #[test]
+    fn test_equivalence_life_board_as_trait_object() {
+        let board = BaseLifeBoard::from_bool_matrix([[true, false], [false, false]], Cell::from_bool).unwrap();
+        let parallel_board = ParallelLifeBoard::from_board(board.clone(), 2);
+        let boards: [&dyn LifeBoard<Cell>; 2] = [&board, &parallel_board];
+        for board in boards {
+            assert_eq!((Ok(true), Ok(false)), (board.is_cell_alive(0, 0), board.is_cell_alive(1, 1)));
+            assert!(board.is_cell_alive(2, 0).is_err(), "Cell should be invalid");
         }
     }
 
     #[test]
     fn test_exception_life_board_is_cell_alive_invalid_x() {
         let board = BaseLifeBoard::from_bool_matrix([[true]], Cell::from_bool).unwrap();
-        if let Some(_) = board._is_cell_alive(-1, 0) {
+        if board._is_cell_alive(-1, 0).is_some() {
             panic!("Cell should be invalid")
         }
     }
 
+    #[test]
+    fn test_equivalence_cell_at_valid_index() {
+        let board = get_3x3_start_board();
+        assert_eq!(&Cell::from_bool(true), board.cell_at(2, 0).unwrap());
+        assert_eq!(&Cell::from_bool(false), board.cell_at(2, 2).unwrap());
+        let board = ParallelLifeBoard::from_board(board, 2);
+        assert_eq!(&Cell::from_bool(true), board.cell_at(2, 0).unwrap());
+    }
+
+    #[test]
+    fn test_equivalence_cell_at_point_matches_tuple() {
+        let board = get_3x3_start_board();
+        for (x, y) in [(2, 0), (2, 2), (0, 1)] {
+            assert_eq!(board.cell_at(x, y).unwrap(), board.cell_at_point(Point::from((x, y))).unwrap());
+            assert_eq!(board.is_cell_alive(x, y), board.is_cell_alive_at_point(Point::new(x, y)));
+        }
+        match board.cell_at_point(Point::new(0, 3)) {
+            Err(LifeBoardError::InvalidIndex(error)) => assert_contains(error, "(0, 3)"),
+            result => panic!("Expected an InvalidIndex error but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_exception_point_from_negative_coordinates() {
+        assert_eq!(Point::new(2, 1), Point::try_from((2i64, 1i64)).unwrap());
+        match Point::try_from((2i64, -1i64)) {
+            Err(LifeBoardError::InvalidIndex(error)) => assert_contains(error, "(2, -1)"),
+            result => panic!("Expected an InvalidIndex error but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_exception_cell_at_invalid_index() {
+        let board = get_3x3_start_board();
+        match board.cell_at(board.width(), 0) {
+            Err(LifeBoardError::InvalidIndex(error)) => assert_contains(error, "(3, 0)"),
+            result => panic!("Expected an InvalidIndex error but found {result:?}"),
+        }
+        match ParallelLifeBoard::from_board(board, 2).cell_at(0, 3) {
+            Err(LifeBoardError::InvalidIndex(error)) => assert_contains(error, "(0, 3)"),
+            result => panic!("Expected an InvalidIndex error but found {result:?}"),
+        }
+    }
+
     #[test]
     fn test_boundary_get_num_alive_neighbors_1x1_board() {
         let board = BaseLifeBoard::from_bool_matrix([[true]], Cell::from_bool).unwrap();
         match board.num_alive_neighbors_at(0, 0) {
-            0 => return,
-            num => panic!("There should be no alive neighbors but found {num}."),
+            Ok(0) => (),
+            result => panic!("There should be no alive neighbors but found {result:?}."),
         }
     }
 
@@ -452,8 +1506,8 @@ mod tests {
     fn test_equivalence_get_num_alive_neighbors_3x3_board_none() {
         let board = get_3x3_board([[false, false, false], [false, false, false], [false, false, false]]);
         match board.num_alive_neighbors_at(1, 1) {
-            0 => return,
-            num => panic!("There should be no alive neighbors but found {num}."),
+            Ok(0) => (),
+            result => panic!("There should be no alive neighbors but found {result:?}."),
         }
     }
 
@@ -461,8 +1515,63 @@ mod tests {
     fn test_equivalence_get_num_alive_neighbors_3x3_board_all() {
         let board = get_3x3_board([[true, true, true], [true, false, true], [true, true, true]]);
         match board.num_alive_neighbors_at(1, 1) {
-            8 => return,
-            num => panic!("Expected 8 neighbors but found {num}"),
+            Ok(8) => (),
+            result => panic!("Expected 8 neighbors but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_equivalence_get_num_alive_neighbors_von_neumann_surrounded() {
+        let mut board = get_3x3_board([[true, true, true], [true, true, true], [true, true, true]]);
+        board.set_neighborhood(Neighborhood::VonNeumann).unwrap();
+        assert_eq!(4, board.num_alive_neighbors_at(1, 1).unwrap());
+        assert_eq!(2, board.num_alive_neighbors_at(0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_equivalence_get_num_alive_neighbors_moore_radius_2() {
+        let mut board = BaseLifeBoard::from_bool_matrix(vec![vec![true; 5]; 5], Cell::from_bool).unwrap();
+        board.set_neighborhood(Neighborhood::MooreRadius(2)).unwrap();
+        assert_eq!(24, board.num_alive_neighbors_at(2, 2).unwrap());
+        assert_eq!(8, board.num_alive_neighbors_at(0, 0).unwrap());
+        board.set_neighborhood(Neighborhood::MooreRadius(1)).unwrap();
+        assert_eq!(8, board.num_alive_neighbors_at(2, 2).unwrap());
+    }
+
+    #[test]
+    fn test_boundary_moore_radius_largest_countable() {
+        let mut board = BaseLifeBoard::from_bool_matrix(vec![vec![true; 15]; 15], Cell::from_bool).unwrap();
+        board.set_neighborhood(Neighborhood::MooreRadius(Neighborhood::MAX_RADIUS)).unwrap();
+        assert_eq!(224, board.num_alive_neighbors_at(7, 7).unwrap());
+        match board.set_neighborhood(Neighborhood::MooreRadius(8)) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("radius 8"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+        assert_eq!(Neighborhood::MooreRadius(7), board.neighborhood());
+    }
+
+    #[test]
+    fn test_equivalence_parallel_von_neumann_matches_base() {
+        let mut board = get_7x7_start_board_0th_gen();
+        board.set_neighborhood(Neighborhood::VonNeumann).unwrap();
+        let mut parallel_board = ParallelLifeBoard::from_board(get_7x7_start_board_0th_gen(), 3);
+        parallel_board.set_neighborhood(Neighborhood::VonNeumann).unwrap();
+        board.simulate_n_steps(5);
+        parallel_board.simulate_n_steps(5);
+        assert_eq!(Neighborhood::VonNeumann, parallel_board.neighborhood());
+        assert_boards_eq(board, parallel_board.into_board());
+    }
+
+    #[test]
+    fn test_exception_get_num_alive_neighbors_invalid_center() {
+        let board = get_3x3_board([[true, true, true], [true, false, true], [true, true, true]]);
+        match board.num_alive_neighbors_at(3, 0) {
+            Err(LifeBoardError::InvalidIndex(error)) => assert_contains(error, "(3, 0)"),
+            result => panic!("Expected an InvalidIndex error but found {result:?}"),
+        }
+        match ParallelLifeBoard::from_board(board, 2).next_cell_state_at(0, 3) {
+            Err(LifeBoardError::InvalidIndex(error)) => assert_contains(error, "(0, 3)"),
+            result => panic!("Expected an InvalidIndex error but found {result:?}"),
         }
     }
 
@@ -532,42 +1641,6 @@ mod tests {
         assert_boards_eq(expected_board, actual_board);
     }
 
-    fn get_7x7_start_board_0th_gen() -> BaseLifeBoard<Cell> {
-        BaseLifeBoard::from_bool_matrix([
-            [false, true, false, true, false, false, false],
-            [false, true, false, false, true, false, false],
-            [false, false, false, false, false, true, false],
-            [false, false, false, false, false, true, false],
-            [true, false, false, false, true, true, false],
-            [true, false, true, false, false, false, false],
-            [false, false, true, true, true, false, true],
-        ], Cell::from_bool).unwrap()
-    }
-
-    fn get_7x7_board_1st_gen() -> BaseLifeBoard<Cell> {
-        BaseLifeBoard::from_bool_matrix([
-            [false, false, true, false, false, false, false],
-            [false, false, true, false, true, false, false],
-            [false, false, false, false, true, true, false],
-            [false, false, false, false, false, true, true],
-            [false, true, false, false, true, true, false],
-            [false, false, true, false, false, false, false],
-            [false, true, true, true, false, false, false],
-        ], Cell::from_bool).unwrap()
-    }
-
-    fn get_7x7_end_board_10th_gen() -> BaseLifeBoard<Cell> {
-        BaseLifeBoard::from_bool_matrix([
-            [false, false, true, true, false, false, false],
-            [false, false, true, true, false, false, false],
-            [false, false, true, false, false, false, false],
-            [false, false, true, false, false, false, false],
-            [false, false, true, false, false, true, true],
-            [false, false, false, false, true, false, true],
-            [false, false, false, false, false, true, false],
-        ], Cell::from_bool).unwrap()
-    }
-
     #[test]
     fn test_equivalence_simulate_7x7_board_10_steps() {
         let mut actual_board = get_7x7_start_board_0th_gen();
@@ -576,6 +1649,24 @@ mod tests {
         assert_boards_eq(expected_board, actual_board);
     }
 
+    #[test]
+    fn test_equivalence_simulate_with_rule_b3_s23_7x7_board() {
+        let mut board = get_7x7_start_board_0th_gen();
+        for _ in 0..10 {
+            board.simulate_with_rule(|alive, neighbors| matches!((alive, neighbors), (true, 2) | (_, 3)));
+        }
+        assert_boards_eq(get_7x7_end_board_10th_gen(), board);
+    }
+
+    #[test]
+    fn test_equivalence_simulate_with_rule_custom_rules() {
+        let mut board = get_block_board();
+        board.simulate_with_rule(|alive, _| !alive);
+        assert_eq!(12, board.count_alive());
+        board.simulate_with_rule(|_, _| false);
+        assert_eq!(0, board.count_alive());
+    }
+
     #[test]
     fn test_equivalence_parallel_3_threads_simulate_7x7_board_10_steps() {
         let actual_board = get_7x7_start_board_0th_gen();
@@ -603,5 +1694,855 @@ mod tests {
         actual_board.simulate_n_steps(10);
         let expected_board = get_7x7_end_board_10th_gen();
         let expected_board = ParallelLifeBoard::from_board(expected_board, 9);
+        assert_eq!(expected_board, actual_board);
+    }
+
+    #[test]
+    fn test_equivalence_parallel_auto_threads_matches_single_thread() {
+        let mut auto_board = ParallelLifeBoard::from_board_auto(get_7x7_start_board_0th_gen());
+        assert!((1..=7).contains(&auto_board.n_threads()));
+        auto_board.simulate_n_steps(10);
+        let mut single_board = ParallelLifeBoard::from_board(get_7x7_start_board_0th_gen(), 1);
+        single_board.simulate_n_steps(10);
+        assert_eq!(single_board.into_board(), auto_board.into_board());
+    }
+
+    #[test]
+    fn test_equivalence_gen_seeded_is_reproducible() {
+        let board = BaseLifeBoard::gen_seeded(10, 10, 0.5, 42, Cell::from_bool);
+        assert_eq!(board, BaseLifeBoard::gen_seeded(10, 10, 0.5, 42, Cell::from_bool));
+        assert!(board.count_alive() > 0 && board.count_alive() < 100, "{board}");
+    }
+
+    #[test]
+    fn test_boundary_gen_with_density_is_clamped() {
+        assert_eq!(0, BaseLifeBoard::gen_with_density(8, 6, -0.5, Cell::from_bool).count_alive());
+        assert_eq!(0, BaseLifeBoard::gen_with_density(8, 6, f64::NAN, Cell::from_bool).count_alive());
+        assert_eq!(48, BaseLifeBoard::gen_with_density(8, 6, 1.5, Cell::from_bool).count_alive());
+        assert_eq!(48, BaseLifeBoard::gen_seeded(8, 6, 2.0, 7, Cell::from_bool).count_alive());
+    }
+
+    #[test]
+    fn test_boundary_parallel_gen_auto_clamps_to_width() {
+        let board = ParallelLifeBoard::gen_auto(1, 5, Cell::gen);
+        assert_eq!(1, board.n_threads());
+    }
+
+    #[test]
+    fn test_exception_board_eq_different_dimensions() {
+        let narrow_board = BaseLifeBoard::from_bool_matrix([
+            [true, false],
+            [false, true],
+        ], Cell::from_bool).unwrap();
+        let wide_board = BaseLifeBoard::from_bool_matrix([
+            [true, false],
+            [false, true],
+            [false, false],
+        ], Cell::from_bool).unwrap();
+        let tall_board = BaseLifeBoard::from_bool_matrix([
+            [true, false, false],
+            [false, true, false],
+        ], Cell::from_bool).unwrap();
+        assert_ne!(narrow_board, wide_board);
+        assert_ne!(wide_board, narrow_board);
+        assert_ne!(narrow_board, tall_board);
+        assert_ne!(tall_board, narrow_board);
+    }
+
+    #[test]
+    fn test_exception_board_eq_2x2_and_3x3_sharing_top_left() {
+        let small_board = BaseLifeBoard::from_bool_matrix([[true, true], [true, false]], Cell::from_bool).unwrap();
+        let mut large_board = small_board.clone();
+        large_board.resize(3, 3, false).unwrap();
+        assert_eq!(small_board.count_alive(), large_board.count_alive());
+        assert!(small_board != large_board, "A 2x2 board should never equal a 3x3 board");
+        assert!(large_board != small_board, "A 3x3 board should never equal a 2x2 board");
+        let mut cropped_board = large_board.clone();
+        cropped_board.resize(2, 2, false).unwrap();
+        assert!(small_board == cropped_board, "Boards with equal dimensions and cells should be equal");
+    }
+
+    #[test]
+    fn test_equivalence_count_alive_3x3_board() {
+        let board = get_3x3_start_board();
+        assert_eq!(5, board.count_alive());
+        assert_eq!(5, ParallelLifeBoard::from_board(board, 2).count_alive());
+    }
+
+    fn get_block_board() -> BaseLifeBoard<Cell> {
+        BaseLifeBoard::from_bool_matrix([
+            [false, false, false, false],
+            [false, true, true, false],
+            [false, true, true, false],
+            [false, false, false, false],
+        ], Cell::from_bool).unwrap()
+    }
+
+    fn get_blinker_board() -> BaseLifeBoard<Cell> {
+        BaseLifeBoard::from_bool_matrix([
+            [false, false, false, false, false],
+            [false, false, false, false, false],
+            [false, true, true, true, false],
+            [false, false, false, false, false],
+            [false, false, false, false, false],
+        ], Cell::from_bool).unwrap()
+    }
+
+    fn get_r_pentomino_board() -> BaseLifeBoard<Cell> {
+        let mut grid = vec![vec![false; 40]; 40];
+        for (x, y) in [(20, 19), (21, 19), (19, 20), (20, 20), (20, 21)] {
+            grid[x][y] = true;
+        }
+        BaseLifeBoard::from_bool_matrix(grid, Cell::from_bool).unwrap()
+    }
+
+    #[test]
+    fn test_equivalence_simulate_stats_blinker() {
+        let expected_stats = StepStats { births: 2, deaths: 2, survivals: 1 };
+        let mut board = get_blinker_board();
+        assert_eq!(expected_stats, board.simulate_stats());
+        assert_eq!(expected_stats, board.simulate_stats());
+        assert_boards_eq(get_blinker_board(), board);
+        let mut board = ParallelLifeBoard::from_board(get_blinker_board(), 3);
+        assert_eq!(expected_stats, board.simulate_stats());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_equivalence_simulate_stats_3x3_board() {
+        // (1, 2) is born with 3 neighbors, (1, 1) dies of overpopulation and (2, 0) of underpopulation.
+        let expected_stats = StepStats { births: 1, deaths: 2, survivals: 3 };
+        let mut board = get_3x3_start_board();
+        assert_eq!(expected_stats, board.simulate_stats());
+        assert_eq!(Ok(true), board.is_cell_alive(1, 2));
+        assert_eq!(expected_stats, ParallelLifeBoard::from_board(get_3x3_start_board(), 2).simulate_stats());
+    }
+
+    #[test]
+    fn test_equivalence_simulate_streaming_blinker() {
+        let (tx, rx) = mpsc::channel();
+        let mut board = ParallelLifeBoard::from_board(get_blinker_board(), 2);
+        board.simulate_streaming(&tx);
+        drop(tx);
+        let mut events: Vec<CellEvent> = rx.iter().collect();
+        events.sort_by_key(|event| match *event {
+            CellEvent::Born(x, y) => (0, x, y),
+            CellEvent::Died(x, y) => (1, x, y),
+        });
+        let expected_events = vec![CellEvent::Born(1, 2), CellEvent::Born(3, 2), CellEvent::Died(2, 1), CellEvent::Died(2, 3)];
+        assert_eq!(expected_events, events);
+        assert_eq!(3, board.count_alive());
+    }
+
+    #[test]
+    fn test_equivalence_simulate_stats_7x7_board() {
+        let mut board = get_7x7_start_board_0th_gen();
+        let stats = board.simulate_stats();
+        assert_eq!(get_7x7_start_board_0th_gen().count_alive(), stats.deaths + stats.survivals);
+        assert_eq!(get_7x7_board_1st_gen().count_alive(), stats.births + stats.survivals);
+        let mut parallel_board = ParallelLifeBoard::from_board(get_7x7_start_board_0th_gen(), 3);
+        assert_eq!(stats, parallel_board.simulate_stats());
+    }
+
+    #[test]
+    fn test_equivalence_population_statistics_blinker() {
+        let mut board = get_blinker_board();
+        let expected_stats = PopulationStatistics {
+            alive: 3, dead: 22, density: 0.12, births_this_gen: 2, deaths_this_gen: 2, net_change: 0
+        };
+        assert_eq!(expected_stats, board.population_statistics());
+        let (stats, next_board) = board.statistics_after_step();
+        assert_eq!(expected_stats, stats);
+        assert_boards_eq(get_blinker_board(), next_board);
+        assert_ne!(get_blinker_board(), board);
+    }
+
+    #[test]
+    fn test_equivalence_population_statistics_5x5_board_shrinks() {
+        let mut board = BaseLifeBoard::from_bool_matrix([
+            [true, false, false, true, false],
+            [false, false, true, true, false],
+            [true, true, false, false, true],
+            [false, true, true, false, false],
+            [true, false, false, true, false],
+        ], Cell::from_bool).unwrap();
+        let stats = board.population_statistics();
+        assert_eq!(stats.alive as i64 - 11, stats.net_change);
+        assert_eq!(25, stats.alive + stats.dead);
+        assert_eq!(stats.births_this_gen as i64 - stats.deaths_this_gen as i64, stats.net_change);
+    }
+
+    #[test]
+    fn test_equivalence_simulate_until_stable_block_still_life() {
+        let mut board = get_block_board();
+        assert_eq!(Ok(0), board.simulate_until_stable(10));
+        assert_boards_eq(get_block_board(), board);
+    }
+
+    #[test]
+    fn test_equivalence_simulate_until_stable_3x3_board() {
+        let mut board = get_3x3_start_board();
+        assert_eq!(Ok(3), board.simulate_until_stable(10));
+        assert!(board.is_stable(), "Board should be stable");
+    }
+
+    #[test]
+    fn test_exception_simulate_until_stable_blinker_never_settles() {
+        let mut board = get_blinker_board();
+        assert_eq!(Err(()), board.simulate_until_stable(10));
+    }
+
+    #[test]
+    fn test_exception_simulate_until_stable_r_pentomino_exceeds_max_steps() {
+        let mut board = ParallelLifeBoard::from_board(get_r_pentomino_board(), 4);
+        assert_eq!(Err(()), board.simulate_until_stable(50));
+        assert!(board.count_alive() > 5, "The R-pentomino should still be growing");
+    }
+
+    #[test]
+    fn test_equivalence_simulate_until_extinct_5x5_board() {
+        let mut board = BaseLifeBoard::from_bool_matrix([
+            [true, false, false, true, false],
+            [false, false, true, true, false],
+            [true, true, false, false, true],
+            [false, true, true, false, false],
+            [true, false, false, true, false],
+        ], Cell::from_bool).unwrap();
+        assert_eq!(Some(8), board.simulate_until_empty(10));
+        assert_eq!(0, board.count_alive());
+    }
+
+    #[test]
+    fn test_equivalence_simulate_with_history_5x5_board_dies_out() {
+        let mut board = BaseLifeBoard::from_bool_matrix([
+            [true, false, false, true, false],
+            [false, false, true, true, false],
+            [true, true, false, false, true],
+            [false, true, true, false, false],
+            [true, false, false, true, false],
+        ], Cell::from_bool).unwrap();
+        let mut parallel_board = ParallelLifeBoard::from_board(board.clone(), 3);
+        let history = board.simulate_with_history(10);
+        assert_eq!(11, history.len());
+        assert_eq!(11, history[0]);
+        assert_eq!(Some(&0), history.last());
+        assert!(history[5..].iter().all(|&population| population < history[0]), "Population should trend down: {history:?}");
+        assert_eq!(history, parallel_board.simulate_with_history(10));
+    }
+
+    #[test]
+    fn test_equivalence_simulate_n_steps_with_blinker() {
+        let mut board = ParallelLifeBoard::from_board(get_blinker_board(), 2);
+        let mut populations = Vec::new();
+        let mut vertical_phases = Vec::new();
+        board.simulate_n_steps_with(5, |generation, board| {
+            populations.push((generation, board.count_alive()));
+            vertical_phases.push(board.is_cell_alive(2, 1) == Ok(true));
+        });
+        assert_eq!(vec![(1, 3), (2, 3), (3, 3), (4, 3), (5, 3)], populations);
+        assert_eq!(vec![false, true, false, true, false], vertical_phases);
+    }
+
+    #[test]
+    fn test_boundary_simulate_n_steps_with_counts_generations() {
+        let mut board = get_blinker_board();
+        let mut generations = Vec::new();
+        board.simulate_n_steps_with(0, |generation, _| generations.push(generation));
+        assert!(generations.is_empty(), "{generations:?}");
+        board.simulate_n_steps_with(3, |generation, _| generations.push(generation));
+        assert_eq!(vec![1, 2, 3], generations);
+        let dyn_board: &mut dyn LifeBoard<Cell> = &mut board;
+        dyn_board.simulate_n_steps(1);
+        assert_eq!(get_blinker_board(), board);
+    }
+
+    #[test]
+    fn test_equivalence_simulate_to_stability_with_history() {
+        let mut board = get_3x3_start_board();
+        let (history, stable) = board.simulate_to_stability_with_history(10);
+        assert!(stable, "Board should settle");
+        assert_eq!(4, history.len());
+        assert_eq!(Some(&board.count_alive()), history.last());
+        let mut board = ParallelLifeBoard::from_board(get_blinker_board(), 2);
+        assert_eq!((vec![3; 6], false), board.simulate_to_stability_with_history(5));
+    }
+
+    #[test]
+    fn test_equivalence_simulate_until_empty_diagonal_dies_at_step_3() {
+        let grid = (0..7).map(|x| (0..7).map(|y| x == y && (1..6).contains(&x)).collect::<Vec<_>>());
+        let board = BaseLifeBoard::from_bool_matrix(grid, Cell::from_bool).unwrap();
+        assert_eq!(Some(3), board.clone().simulate_until_empty(10));
+        assert_eq!(Some(3), ParallelLifeBoard::from_board(board.clone(), 3).simulate_until_empty(10));
+        assert_eq!(None, board.clone().simulate_until_empty(2));
+        assert_eq!(Some(0), BaseLifeBoard::gen_with_density(3, 3, 0.0, Cell::from_bool).simulate_until_empty(0));
+    }
+
+    #[test]
+    fn test_equivalence_simulate_until_cycle_blinker_period_2() {
+        assert_eq!(Some((0, 2)), get_blinker_board().simulate_until_cycle(10));
+        assert_eq!(Some((0, 2)), ParallelLifeBoard::from_board(get_blinker_board(), 2).simulate_until_cycle(10));
+        assert_eq!(Some((0, 1)), get_block_board().simulate_until_cycle(10));
+        assert_eq!(None, get_blinker_board().simulate_until_cycle(1));
+        let mut board = get_3x3_start_board();
+        let (cycle_start, period) = board.simulate_until_cycle(10).unwrap();
+        assert_eq!((3, 1), (cycle_start, period), "{board}");
+    }
+
+    #[test]
+    fn test_equivalence_board_hash_matches_state_hash() {
+        let board = get_7x7_start_board_0th_gen();
+        let mut hasher = DefaultHasher::new();
+        board.hash(&mut hasher);
+        assert_eq!(board.state_hash(), hasher.finish());
+        assert_eq!(board.state_hash(), ParallelLifeBoard::from_board(board.clone(), 3).state_hash());
+        assert_ne!(board.state_hash(), board.bitwise_not().state_hash());
+    }
+
+    #[test]
+    fn test_exception_simulate_until_extinct_block_survives() {
+        let mut board = ParallelLifeBoard::from_board(get_block_board(), 2);
+        assert_eq!(Err(()), board.simulate_until_extinct(10));
+        assert_eq!(4, board.count_alive());
+    }
+
+    fn get_single_cell_board(width: usize, height: usize, x: usize, y: usize) -> BaseLifeBoard<Cell> {
+        let mut grid = vec![vec![false; height]; width];
+        grid[x][y] = true;
+        BaseLifeBoard::from_bool_matrix(grid, Cell::from_bool).unwrap()
+    }
+
+    #[test]
+    fn test_equivalence_translate_wrap_full_width_returns_to_origin() {
+        let mut board = get_single_cell_board(5, 4, 1, 2);
+        board.translate(5, 0, true);
+        assert_boards_eq(get_single_cell_board(5, 4, 1, 2), board.clone());
+        board.translate(-10, 8, true);
+        assert_boards_eq(get_single_cell_board(5, 4, 1, 2), board);
+    }
+
+    #[test]
+    fn test_equivalence_translate_wrap_across_edges() {
+        let mut board = get_single_cell_board(5, 4, 4, 0);
+        board.translate(2, -1, true);
+        assert_boards_eq(get_single_cell_board(5, 4, 1, 3), board);
+    }
+
+    fn get_edge_glider_board(wrap_x: bool, wrap_y: bool) -> ParallelLifeBoard<Cell> {
+        let mut board = patterns::glider(Cell::from_bool);
+        board.resize(10, 10, false).unwrap();
+        board.translate(7, 5, false);
+        board.set_wrap(wrap_x, wrap_y);
+        ParallelLifeBoard::from_board(board, 2)
+    }
+
+    #[test]
+    fn test_equivalence_wrap_x_glider_crosses_right_edge_but_not_top() {
+        let mut board = get_edge_glider_board(true, false);
+        let mut expected_board = board.clone().into_board();
+        expected_board.translate(3, -3, true);
+        board.simulate_n_steps(12);
+        assert_boards_eq(expected_board, board.clone().into_board());
+        // Without vertical wrapping the glider runs into the top edge and settles into a block.
+        board.simulate_n_steps(16);
+        let mut torus_board = get_edge_glider_board(true, true);
+        torus_board.simulate_n_steps(28);
+        assert_eq!((4, true, 5), (board.count_alive(), board.is_stable(), torus_board.count_alive()));
+        assert_eq!((true, false), (board.wrap_x(), board.wrap_y()));
+    }
+
+    #[test]
+    fn test_equivalence_translate_fill_dead() {
+        let mut board = get_single_cell_board(5, 4, 1, 2);
+        board.translate(-1, 1, false);
+        assert_boards_eq(get_single_cell_board(5, 4, 0, 3), board.clone());
+        board.translate(-1, 0, false);
+        assert_eq!(0, board.count_alive());
+    }
+
+    #[test]
+    fn test_equivalence_shift_block_one_column() {
+        let mut board = get_block_board();
+        board.shift(1, 0);
+        let expected_board = BaseLifeBoard::from_bool_matrix([
+            [false, false, false, false],
+            [false, false, false, false],
+            [false, true, true, false],
+            [false, true, true, false],
+        ], Cell::from_bool).unwrap();
+        assert_boards_eq(expected_board, board.clone());
+        board.shift(-2, 2);
+        let expected_board = BaseLifeBoard::from_bool_matrix([
+            [false, false, false, true],
+            [false, false, false, true],
+            [false, false, false, false],
+            [false, false, false, false],
+        ], Cell::from_bool).unwrap();
+        assert_boards_eq(expected_board, board);
+    }
+
+    fn get_glider_board() -> BaseLifeBoard<Cell> {
+        get_3x3_board([
+            [false, false, true],
+            [true, false, true],
+            [false, true, true],
+        ])
+    }
+
+    #[test]
+    fn test_equivalence_live_bounds() {
+        assert_eq!(Some((2..3, 1..4)), get_blinker_board().live_bounds());
+        assert_eq!(Some((0..3, 0..3)), get_glider_board().live_bounds());
+        let empty_board = BaseLifeBoard::from_bool_matrix([[false; 3]; 3], Cell::from_bool).unwrap();
+        assert_eq!(None, empty_board.live_bounds());
+    }
+
+    #[test]
+    fn test_equivalence_trimmed_glider_in_20x20_board() {
+        let mut board = get_glider_board();
+        board.resize(20, 20, false).unwrap();
+        board.translate(8, 11, false);
+        assert_boards_eq(get_glider_board(), board.trimmed().unwrap());
+    }
+
+    #[test]
+    fn test_equivalence_equivalent_to_glider_after_full_period() {
+        let mut glider = get_glider_board();
+        glider.resize(12, 12, false).unwrap();
+        let start = glider.clone();
+        glider.simulate_n_steps(4);
+        assert_ne!(start, glider);
+        assert!(start.equivalent_to(&glider), "A glider should match itself one period later");
+        assert!(glider.canonical().unwrap().equivalent_to(&start));
+        glider.simulate();
+        assert!(!start.equivalent_to(&glider), "Different phases should not be equivalent");
+    }
+
+    #[test]
+    fn test_equivalence_canonical_moves_pattern_to_origin() {
+        let board = get_single_cell_board(5, 4, 3, 2);
+        assert_boards_eq(get_single_cell_board(1, 1, 0, 0), board.canonical().unwrap());
+        let empty_board = BaseLifeBoard::from_bool_matrix(vec![vec![false; 3]; 6], Cell::from_bool).unwrap();
+        let canonical_board = empty_board.canonical().unwrap();
+        assert_eq!((1, 1, 0), (canonical_board.width(), canonical_board.height(), canonical_board.count_alive()));
+        assert!(empty_board.equivalent_to(&get_3x3_board([[false; 3]; 3])));
+        assert!(!empty_board.equivalent_to(&board));
+    }
+
+    #[test]
+    fn test_boundary_trimmed_empty_board() {
+        let board = BaseLifeBoard::from_bool_matrix([[false; 4]; 4], Cell::from_bool).unwrap();
+        assert!(board.trimmed().is_none(), "Empty board should not trim");
+    }
+
+    fn get_l_shape_board() -> BaseLifeBoard<Cell> {
+        BaseLifeBoard::from_ascii("O..\nO..\nOO.\n...\n", 'O', Cell::from_bool).unwrap()
+    }
+
+    #[test]
+    fn test_equivalence_flipped_horizontal_l_shape() {
+        let expected_board = BaseLifeBoard::from_ascii("..O\n..O\n.OO\n...\n", 'O', Cell::from_bool).unwrap();
+        assert_boards_eq(expected_board, get_l_shape_board().flipped_horizontal());
+        assert_boards_eq(get_l_shape_board(), get_l_shape_board().flipped_horizontal().flipped_horizontal());
+    }
+
+    #[test]
+    fn test_equivalence_flipped_vertical_l_shape() {
+        let expected_board = BaseLifeBoard::from_ascii("...\nOO.\nO..\nO..\n", 'O', Cell::from_bool).unwrap();
+        assert_boards_eq(expected_board, get_l_shape_board().flipped_vertical());
+        assert_boards_eq(get_l_shape_board(), get_l_shape_board().flipped_vertical().flipped_vertical());
+    }
+
+    #[test]
+    fn test_equivalence_combine_or_blinker_phases_is_plus() {
+        let mut other_phase = get_blinker_board();
+        other_phase.simulate();
+        let expected_board = BaseLifeBoard::from_bool_matrix([
+            [false, false, false, false, false],
+            [false, false, true, false, false],
+            [false, true, true, true, false],
+            [false, false, true, false, false],
+            [false, false, false, false, false],
+        ], Cell::from_bool).unwrap();
+        assert_boards_eq(expected_board, get_blinker_board().combine(&other_phase, BoolOp::Or).unwrap());
+        let center = get_blinker_board().combine(&other_phase, BoolOp::And).unwrap();
+        assert_boards_eq(get_single_cell_board(5, 5, 2, 2), center);
+    }
+
+    #[test]
+    fn test_equivalence_combine_or_single_cells() {
+        let board = get_single_cell_board(4, 3, 0, 0).combine(&get_single_cell_board(4, 3, 3, 2), BoolOp::Or).unwrap();
+        assert_eq!(2, board.count_alive());
+        assert_eq!(Ok(true), board.is_cell_alive(0, 0));
+        assert_eq!(Ok(true), board.is_cell_alive(3, 2));
+    }
+
+    #[test]
+    fn test_equivalence_combine_xor_with_itself_is_dead() {
+        let board = get_7x7_start_board_0th_gen();
+        assert_eq!(0, board.combine(&board, BoolOp::Xor).unwrap().count_alive());
+    }
+
+    #[test]
+    fn test_equivalence_bitwise_ops_with_complement() {
+        let board = get_7x7_start_board_0th_gen();
+        let all_alive = (&board | &!&board).unwrap();
+        assert_eq!(49, all_alive.count_alive());
+        assert_eq!(0, (&board & &board.bitwise_not()).unwrap().count_alive());
+        assert_eq!(all_alive, (&board ^ &!board.clone()).unwrap());
+        assert_boards_eq(board.clone(), !!board.clone());
+        assert_boards_eq(board.clone(), board.bitwise_and(&all_alive).unwrap());
+        assert_boards_eq(board.clone(), board.bitwise_or(&board).unwrap());
+        assert_eq!(0, board.bitwise_xor(&board).unwrap().count_alive());
+    }
+
+    #[test]
+    fn test_exception_bitwise_ops_different_dimensions() {
+        for result in [&get_block_board() | &get_blinker_board(), &get_block_board() & &get_blinker_board(), &get_block_board() ^ &get_blinker_board()] {
+            assert!(matches!(result, Err(LifeBoardError::InvalidBoard(_))), "Dimensions should not match");
+        }
+    }
+
+    #[test]
+    fn test_equivalence_diff_blinker_phases() {
+        let mut other_phase = get_blinker_board();
+        other_phase.simulate();
+        let expected_diff = vec![(1, 2, true), (2, 1, false), (2, 3, false), (3, 2, true)];
+        assert_eq!(expected_diff, get_blinker_board().diff(&other_phase).unwrap());
+        assert_eq!(Vec::<(usize, usize, bool)>::new(), other_phase.diff(&other_phase).unwrap());
+    }
+
+    #[test]
+    fn test_exception_diff_different_dimensions() {
+        match get_block_board().diff(&get_blinker_board()) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("4x4") && error.contains("5x5"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_exception_combine_different_dimensions() {
+        match get_block_board().combine(&get_blinker_board(), BoolOp::Or) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("4x4") && error.contains("5x5"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_equivalence_simulate_expanding_glider_survives() {
+        let mut board = get_glider_board();
+        for step in 0..120 {
+            board.simulate_expanding(3);
+            assert_eq!(5, board.count_alive(), "Glider should keep 5 cells at step {step}");
+        }
+        let (x_range, y_range) = board.live_bounds().unwrap();
+        assert_eq!((3, 3), (x_range.len(), y_range.len()));
+        assert!(x_range.start >= 3 && board.width() - x_range.end >= 3, "Board should keep a margin");
+        assert!(y_range.start >= 3 && board.height() - y_range.end >= 3, "Board should keep a margin");
+        assert!(board.width() > 30 && board.height() > 30, "Board should have grown");
+    }
+
+    #[test]
+    fn test_equivalence_auto_expand_glider_40_steps() {
+        let glider = get_glider_board();
+        let mut board = glider.clone();
+        board.set_auto_expand(Some(2));
+        let mut parallel_board = ParallelLifeBoard::from_board(board.clone(), 3);
+        board.simulate_n_steps(40);
+        parallel_board.simulate_n_steps(40);
+        assert_eq!(glider.trimmed(), board.trimmed());
+        assert!(board.width() > glider.width() && board.height() > glider.height(), "Board should have grown");
+        assert_eq!(ParallelLifeBoard::<Cell>::row_ranges(board.width(), 3), parallel_board.thread_row_ranges);
+        assert_eq!(Some(2), parallel_board.auto_expand());
+        assert_boards_eq(board, parallel_board.into_board());
+    }
+
+    #[test]
+    fn test_equivalence_parallel_simulate_expanding() {
+        let mut expected_board = get_7x7_start_board_0th_gen();
+        let mut actual_board = ParallelLifeBoard::from_board(get_7x7_start_board_0th_gen(), 3);
+        for _ in 0..20 {
+            expected_board.simulate_expanding(2);
+            actual_board.simulate_expanding(2);
+        }
+        let expected_ranges = ParallelLifeBoard::<Cell>::row_ranges(expected_board.width(), 3);
+        assert_eq!(expected_ranges, actual_board.thread_row_ranges);
+        assert_eq!(ParallelLifeBoard::from_board(expected_board, 3), actual_board);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_equivalence_serde_round_trip_50x50_board() {
+        let board = BaseLifeBoard::gen(50, 50, Cell::gen);
+        let json = serde_json::to_string(&board).unwrap();
+        let parsed: BaseLifeBoard<Cell> = serde_json::from_str(&json).unwrap();
+        assert_boards_eq(board, parsed);
+
+        let board = ParallelLifeBoard::from_board(BaseLifeBoard::gen(50, 50, Cell::gen), 4);
+        let json = serde_json::to_string(&board).unwrap();
+        let parsed: ParallelLifeBoard<Cell> = serde_json::from_str(&json).unwrap();
+        assert_eq!(board, parsed);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_exception_serde_ragged_board() {
+        let json = r#"{"grid": [[{"alive": true}, {"alive": false}], [{"alive": true}]]}"#;
+        match serde_json::from_str::<BaseLifeBoard<Cell>>(json) {
+            Ok(board) => panic!("Board should be invalid but found {board:?}"),
+            Err(error) => assert_contains(error.to_string(), "consistent size"),
+        }
+        match serde_json::from_str::<BaseLifeBoard<Cell>>(r#"{"grid": []}"#) {
+            Ok(board) => panic!("Board should be invalid but found {board:?}"),
+            Err(error) => assert_contains(error.to_string(), "at least one cell wide"),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_exception_serde_parallel_board_without_threads() {
+        let json = r#"{"board": {"grid": [[{"alive": true}]]}, "n_threads": 0}"#;
+        match serde_json::from_str::<ParallelLifeBoard<Cell>>(json) {
+            Ok(board) => panic!("Board should be invalid but found {board:?}"),
+            Err(error) => assert_contains(error.to_string(), "at least one thread"),
+        }
+    }
+
+    #[test]
+    fn test_equivalence_resize_3x3_board_grow() {
+        let mut actual_board = get_3x3_start_board();
+        actual_board.resize(5, 4, false).unwrap();
+        let expected_board = BaseLifeBoard::from_bool_matrix([
+            [true, true, true, false],
+            [false, true, false, false],
+            [true, false, false, false],
+            [false, false, false, false],
+            [false, false, false, false],
+        ], Cell::from_bool).unwrap();
+        assert_eq!(5, actual_board.width());
+        assert_eq!(4, actual_board.height());
+        assert_boards_eq(expected_board, actual_board);
+    }
+
+    #[test]
+    fn test_equivalence_resize_3x3_board_grow_alive_fill() {
+        let mut actual_board = get_3x3_start_board();
+        actual_board.resize(4, 3, true).unwrap();
+        let expected_board = BaseLifeBoard::from_bool_matrix([
+            [true, true, true],
+            [false, true, false],
+            [true, false, false],
+            [true, true, true],
+        ], Cell::from_bool).unwrap();
+        assert_boards_eq(expected_board, actual_board);
+    }
+
+    #[test]
+    fn test_equivalence_resize_3x3_board_shrink() {
+        let mut actual_board = get_3x3_start_board();
+        actual_board.resize(2, 2, true).unwrap();
+        let expected_board = BaseLifeBoard::from_bool_matrix([
+            [true, true],
+            [false, true],
+        ], Cell::from_bool).unwrap();
+        assert_eq!(2, actual_board.width());
+        assert_eq!(2, actual_board.height());
+        assert_boards_eq(expected_board, actual_board);
+    }
+
+    #[test]
+    fn test_exception_resize_zero_dimensions() {
+        let mut board = get_3x3_start_board();
+        match board.resize(0, 3, false) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert_contains(error, "at least one cell wide"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+        match board.resize(3, 0, false) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert_contains(error, "at least one cell tall"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+        assert_boards_eq(get_3x3_start_board(), board);
+    }
+
+    #[test]
+    fn test_equivalence_parallel_column_split_matches_row_split() {
+        let board = BaseLifeBoard::gen(4, 400, Cell::gen);
+        let mut row_board = ParallelLifeBoard::from_board(board.clone(), 8);
+        let mut column_board = ParallelLifeBoard::from_board(board, 8);
+        column_board.set_split_axis(SplitAxis::Columns);
+        assert_eq!(vec![50; 8], column_board.thread_row_ranges.iter().map(|range| range.len()).collect::<Vec<_>>());
+        for _ in 0..5 {
+            assert_eq!(row_board.simulate_stats(), column_board.simulate_stats());
+        }
+        assert_eq!(row_board.into_board(), column_board.into_board());
+    }
+
+    #[test]
+    fn test_equivalence_parallel_segments_match_base_board_10_steps() {
+        let board = BaseLifeBoard::gen_seeded(23, 17, 0.4, 11, Cell::from_bool);
+        for (n_threads, split_axis) in [(1, SplitAxis::Rows), (4, SplitAxis::Rows), (5, SplitAxis::Columns), (17, SplitAxis::Columns)] {
+            let mut base_board = board.clone();
+            let mut parallel_board = ParallelLifeBoard::from_board(board.clone(), n_threads);
+            parallel_board.set_split_axis(split_axis);
+            for _ in 0..10 {
+                assert_eq!(base_board.simulate_stats(), parallel_board.simulate_stats(), "{n_threads} threads, {split_axis:?}");
+            }
+            assert_boards_eq(base_board, parallel_board.into_board());
+        }
+    }
+
+    #[test]
+    fn test_equivalence_parallel_every_thread_count_matches_base_7x7_board() {
+        for n_threads in 1..=7 {
+            let mut base_board = get_7x7_start_board_0th_gen();
+            let mut parallel_board = ParallelLifeBoard::from_board(get_7x7_start_board_0th_gen(), n_threads);
+            for _ in 0..10 {
+                base_board.simulate();
+                parallel_board.simulate();
+                assert_eq!(base_board.to_vec_matrix(), parallel_board.to_vec_matrix(), "{n_threads} threads");
+            }
+        }
+    }
+
+    #[test]
+    fn test_equivalence_set_thread_count_covers_every_row_once() {
+        let mut board = ParallelLifeBoard::from_board(BaseLifeBoard::gen(10, 3, Cell::gen), 2);
+        board.set_thread_count(4).unwrap();
+        assert_eq!(4, board.n_threads());
+        assert_eq!(4, board.thread_ranges().len());
+        let mut rows: Vec<usize> = board.thread_ranges().iter().flat_map(Range::clone).collect();
+        rows.sort_unstable();
+        assert_eq!((0..10).collect::<Vec<_>>(), rows);
+    }
+
+    #[test]
+    fn test_exception_set_thread_count_zero() {
+        let mut board = ParallelLifeBoard::from_board(BaseLifeBoard::gen(10, 3, Cell::gen), 2);
+        match board.set_thread_count(0) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("at least one thread"), "{error}"),
+            result => panic!("Expected an invalid board error but found {result:?}"),
+        }
+        assert_eq!(2, board.n_threads());
+    }
+
+    #[test]
+    fn test_equivalence_parallel_column_split_resize_rebuilds_ranges() {
+        let mut board = ParallelLifeBoard::from_board(BaseLifeBoard::gen(4, 9, Cell::gen), 2);
+        board.set_split_axis(SplitAxis::Columns);
+        board.resize(4, 7, false).unwrap();
+        assert_eq!(vec![0..4, 4..7], board.thread_row_ranges);
+        assert_eq!(SplitAxis::Columns, board.split_axis());
+    }
+
+    #[test]
+    fn test_equivalence_row_ranges_balanced() {
+        let lengths = |width, n_threads| ParallelLifeBoard::<Cell>::row_ranges(width, n_threads).iter().map(|range| range.len()).collect::<Vec<_>>();
+        assert_eq!(vec![4, 3, 3], lengths(10, 3));
+        assert_eq!(vec![15, 15, 14, 14, 14, 14, 14], lengths(100, 7));
+        assert_eq!(vec![1, 1, 0, 0], lengths(2, 4));
+        assert_eq!(vec![0..3, 3..5, 5..7], ParallelLifeBoard::<Cell>::row_ranges(7, 3));
+    }
+
+    #[test]
+    fn test_equivalence_parallel_resize_rebuilds_thread_ranges() {
+        let mut actual_board = ParallelLifeBoard::from_board(get_3x3_start_board(), 3);
+        actual_board.resize(7, 7, false).unwrap();
+        assert_eq!(vec![0..3, 3..5, 5..7], actual_board.thread_row_ranges);
+        let mut expected_board = get_3x3_start_board();
+        expected_board.resize(7, 7, false).unwrap();
+        actual_board.simulate_n_steps(5);
+        expected_board.simulate_n_steps(5);
+        assert_eq!(ParallelLifeBoard::from_board(expected_board, 3), actual_board);
+    }
+
+    #[test]
+    fn test_equivalence_index_reads_cells() {
+        let board = get_7x7_start_board_0th_gen();
+        assert!(board[(0, 1)].is_alive());
+        assert!(!board[(0, 0)].is_alive());
+        assert_eq!(board.cell_at(6, 6).unwrap(), &board[(6, 6)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cell (7, 0) is outside of the 7x7 board.")]
+    fn test_exception_index_out_of_bounds() {
+        let board = get_7x7_start_board_0th_gen();
+        let _ = &board[(7, 0)];
+    }
+
+    #[test]
+    fn test_equivalence_iterate_rows() {
+        let board = BaseLifeBoard::gen_seeded(9, 4, 0.5, 6, Cell::from_bool);
+        assert_eq!(9, board.rows().count());
+        let mut states = Vec::new();
+        for row in &board {
+            assert_eq!(4, row.len());
+            states.push(row.iter().map(LifeCell::is_alive).collect::<Vec<_>>());
+        }
+        assert_eq!(board.to_bool_matrix(), states);
+    }
+
+    #[test]
+    fn test_boundary_zero_height_board_has_empty_columns() {
+        let board = BaseLifeBoard::gen(3, 0, Cell::gen);
+        assert_eq!(vec![Vec::<Cell>::new(); 3], board.to_vec_matrix());
+        assert_eq!(3, board.rows().rev().filter(|row| row.is_empty()).count());
+        assert_eq!(None, board.live_bounds());
+        assert!(board.trimmed().is_none());
+        let mut parallel_board = ParallelLifeBoard::from_board(board, 2);
+        parallel_board.set_split_axis(SplitAxis::Columns);
+        parallel_board.simulate();
+        assert_eq!(vec![Vec::<Cell>::new(); 3], parallel_board.to_vec_matrix());
+    }
+
+    #[test]
+    fn test_exception_zero_height_board_resize() {
+        let mut board = BaseLifeBoard::gen(3, 0, Cell::gen);
+        match board.resize(4, 4, false) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert_contains(error, "3x0 board has no cell"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+        assert_eq!((3, 0), (board.width(), board.height()));
+        let mut parallel_board = ParallelLifeBoard::from_board(board, 2);
+        match parallel_board.resize(4, 4, true) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert_contains(error, "3x0 board has no cell"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+        assert_eq!((3, 0), (parallel_board.width(), parallel_board.height()));
+    }
+
+    #[test]
+    fn test_exception_zero_height_board_canonical() {
+        let board = BaseLifeBoard::gen(3, 0, Cell::gen);
+        match board.canonical() {
+            Err(LifeBoardError::InvalidBoard(error)) => assert_contains(error, "3x0 board has no canonical form"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+        assert!(board.equivalent_to(&BaseLifeBoard::gen(0, 5, Cell::gen)));
+        assert!(board.equivalent_to(&get_3x3_board([[false; 3]; 3])));
+        assert!(!board.equivalent_to(&get_3x3_start_board()));
+    }
+
+    #[test]
+    fn test_boundary_zero_height_board_auto_expand() {
+        let mut board = BaseLifeBoard::gen(3, 0, Cell::gen);
+        board.simulate_expanding(2);
+        board.set_auto_expand(Some(2));
+        board.simulate();
+        assert_eq!((3, 0), (board.width(), board.height()));
+    }
+
+    #[test]
+    fn test_equivalence_try_from_rows_mapped_iterator() {
+        let board = BaseLifeBoard::try_from_rows((0..3).map(|x| (0..3).map(|y| Cell::from_bool(x == y)).collect())).unwrap();
+        assert_eq!(get_3x3_board([[true, false, false], [false, true, false], [false, false, true]]), board);
+        assert_eq!(board, BaseLifeBoard::try_from_rows(board.rows().map(<[Cell]>::to_vec)).unwrap());
+    }
+
+    #[test]
+    fn test_exception_try_from_rows_inconsistent_length() {
+        let rows = vec![vec![Cell::from_bool(true); 3], vec![Cell::from_bool(false); 2], vec![Cell::from_bool(true); 3]];
+        match BaseLifeBoard::try_from_rows(rows) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("consistent size"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+    }
+}
@@ -1,26 +1,415 @@
-use std::fmt::{Debug};
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::marker::PhantomData;
+use std::hash::Hasher;
+use std::ops::Range;
+use std::sync::mpsc;
+use crate::life_format;
 
-pub trait LifeBoard<T: LifeCell<T>>: PartialEq + Clone {
+pub trait LifeBoard<T: LifeCell> {
     fn width(&self) -> usize;
     fn height(&self) -> usize;
     fn simulate(&mut self);
-    fn simulate_n_steps(&mut self, n: usize);
-    fn next_cell_state_at(&self, x:usize, y:usize) -> Option<T>;
-    fn cell_at(&self, x:usize, y:usize) -> Option<T>;
-    fn num_alive_neighbors_at(&self, x: usize, y: usize) -> u8;
-    fn is_cell_alive(&self, x: usize, y: usize) -> Option<bool>;
+
+    fn simulate_stats(&mut self) -> StepStats {
+        let was_alive: Vec<Vec<bool>> = (0..self.width()).map(|x|
+            (0..self.height()).map(|y| self.is_cell_alive(x, y) == Ok(true)).collect()
+        ).collect();
+        self.simulate();
+        let mut stats = StepStats::default();
+        for (x, col) in was_alive.into_iter().enumerate() {
+            for (y, was_alive) in col.into_iter().enumerate() {
+                stats.record(was_alive, self.is_cell_alive(x, y) == Ok(true));
+            }
+        }
+        stats
+    }
+    fn simulate_n_steps(&mut self, n: usize) {
+        for _ in 0..n {
+            self.simulate();
+        }
+    }
+
+    // Sends an event for every cell that changed during the step. Events are dropped if the receiver has hung up.
+    fn simulate_streaming(&mut self, tx: &mpsc::Sender<CellEvent>) {
+        let was_alive: Vec<Vec<bool>> = (0..self.width()).map(|x|
+            (0..self.height()).map(|y| self.is_cell_alive(x, y) == Ok(true)).collect()
+        ).collect();
+        self.simulate();
+        for (x, col) in was_alive.into_iter().enumerate() {
+            for (y, was_alive) in col.into_iter().enumerate() {
+                let event = match (was_alive, self.is_cell_alive(x, y) == Ok(true)) {
+                    (false, true) => CellEvent::Born(x, y),
+                    (true, false) => CellEvent::Died(x, y),
+                    _ => continue,
+                };
+                if tx.send(event).is_err() {
+                    return;
+                }
+            }
+        }
+    }
+    fn next_cell_state_at(&self, x:usize, y:usize) -> Result<T, LifeBoardError>;
+    fn cell_at(&self, x:usize, y:usize) -> Result<&T, LifeBoardError>;
+    fn num_alive_neighbors_at(&self, x: usize, y: usize) -> Result<u8, LifeBoardError>;
+    fn is_cell_alive(&self, x: usize, y: usize) -> Result<bool, LifeBoardError>;
     fn to_vec_matrix(&self) -> Vec<Vec<T>>;
+
+    // Column-major like `to_vec_matrix`, with each cell reduced to whether it is alive.
+    fn to_bool_matrix(&self) -> Vec<Vec<bool>> {
+        (0..self.width()).map(|x| (0..self.height()).map(|y| self.is_cell_alive(x, y) == Ok(true)).collect()).collect()
+    }
+    // Sets every cell of the `w`x`h` region with its top-left corner at (x, y) to alive or dead.
+    fn fill_region(&mut self, x: usize, y: usize, w: usize, h: usize, alive: bool) -> Result<(), LifeBoardError>;
+
+    fn clear(&mut self) {
+        self.fill_region(0, 0, self.width(), self.height(), false).expect("Should always be a valid region");
+    }
+
+    fn fill_alive(&mut self) {
+        self.fill_region(0, 0, self.width(), self.height(), true).expect("Should always be a valid region");
+    }
+
+    fn clear_region(&mut self, x: usize, y: usize, w: usize, h: usize) -> Result<(), LifeBoardError> {
+        self.fill_region(x, y, w, h, false)
+    }
+
+    fn cell_at_point(&self, point: Point) -> Result<&T, LifeBoardError> { self.cell_at(point.x, point.y) }
+
+    fn is_cell_alive_at_point(&self, point: Point) -> Result<bool, LifeBoardError> { self.is_cell_alive(point.x, point.y) }
+
+    fn count_alive(&self) -> usize {
+        (0..self.width()).map(|x|
+            (0..self.height()).filter(|&y| self.is_cell_alive(x, y) == Ok(true)).count()
+        ).sum()
+    }
+
+    fn is_stable(&self) -> bool {
+        (0..self.width()).all(|x|
+            (0..self.height()).all(|y|
+                self.next_cell_state_at(x, y).map(|cell| cell.is_alive()) == self.is_cell_alive(x, y)
+            )
+        )
+    }
+
+    fn to_rle(&self) -> String {
+        life_format::encode_rle(self.width(), self.height(), |x, y| self.is_cell_alive(x, y) == Ok(true))
+    }
+
+    fn to_life106(&self) -> String {
+        life_format::encode_life106(self.width(), self.height(), |x, y| self.is_cell_alive(x, y) == Ok(true))
+    }
+
+    fn to_csv(&self) -> String {
+        life_format::encode_csv(self.width(), self.height(), false, |x, y| self.is_cell_alive(x, y) == Ok(true))
+    }
+
+    fn to_csv_with_header(&self) -> String {
+        life_format::encode_csv(self.width(), self.height(), true, |x, y| self.is_cell_alive(x, y) == Ok(true))
+    }
+
+    fn to_svg(&self, cell_size: f64, alive_fill: &str, dead_fill: Option<&str>, grid_stroke: Option<&str>) -> String {
+        life_format::encode_svg(
+            self.width(), self.height(), cell_size, alive_fill, dead_fill, grid_stroke, |x, y| self.is_cell_alive(x, y) == Ok(true)
+        )
+    }
+
+    // Displays the board with custom symbols, e.g. `println!("{}", board.fmt_with("#", ".", "|"))`.
+    fn fmt_with<'a>(&'a self, alive: &'a str, dead: &'a str, separator: &'a str) -> BoardFormatter<'a, T, Self> where Self: Sized {
+        BoardFormatter { board: self, alive, dead, separator, cell: PhantomData }
+    }
+
+    #[allow(clippy::result_unit_err)]
+    fn simulate_until_stable(&mut self, max_steps: usize) -> Result<usize, ()> {
+        let mut steps = 0;
+        while !self.is_stable() {
+            if steps == max_steps {
+                return Err(());
+            }
+            self.simulate();
+            steps += 1;
+        }
+        Ok(steps)
+    }
+
+    // Calls `on_step` with the generation number (starting at 1) and the board after each of the `n` steps.
+    fn simulate_n_steps_with<F: FnMut(usize, &Self)>(&mut self, n: usize, mut on_step: F) where Self: Sized {
+        for generation in 1..=n {
+            self.simulate();
+            on_step(generation, self);
+        }
+    }
+
+    fn simulate_with_history(&mut self, steps: usize) -> Vec<usize> {
+        let mut history = vec![self.count_alive()];
+        for _ in 0..steps {
+            self.simulate();
+            history.push(self.count_alive());
+        }
+        history
+    }
+
+    fn simulate_to_stability_with_history(&mut self, max_steps: usize) -> (Vec<usize>, bool) {
+        let mut history = vec![self.count_alive()];
+        while !self.is_stable() {
+            if history.len() > max_steps {
+                return (history, false);
+            }
+            self.simulate();
+            history.push(self.count_alive());
+        }
+        (history, true)
+    }
+
+    fn state_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        hash_board_state(self.width(), self.height(), |x, y| self.is_cell_alive(x, y) == Ok(true), &mut hasher);
+        hasher.finish()
+    }
+
+    // Returns `(cycle_start, period)` once a generation repeats an earlier one, or `None` if none repeats within `max_steps` steps.
+    // Generations are compared by `state_hash`, so a hash collision could in principle report a false cycle.
+    fn simulate_until_cycle(&mut self, max_steps: usize) -> Option<(usize, usize)> {
+        let mut seen = HashMap::from([(self.state_hash(), 0)]);
+        for generation in 1..=max_steps {
+            self.simulate();
+            if let Some(cycle_start) = seen.insert(self.state_hash(), generation) {
+                return Some((cycle_start, generation - cycle_start));
+            }
+        }
+        None
+    }
+
+    // Returns the generation at which the population first reaches zero, or `None` if it survives `max_steps` steps.
+    fn simulate_until_empty(&mut self, max_steps: usize) -> Option<usize> {
+        self.simulate_until_extinct(max_steps).ok()
+    }
+
+    #[allow(clippy::result_unit_err)]
+    fn simulate_until_extinct(&mut self, max_steps: usize) -> Result<usize, ()> {
+        let mut steps = 0;
+        while self.count_alive() != 0 {
+            if steps == max_steps {
+                return Err(());
+            }
+            self.simulate();
+            steps += 1;
+        }
+        Ok(steps)
+    }
 }
 
 
-pub trait LifeCell<T: LifeCell<T>>: PartialEq + Clone {
+pub(crate) fn region_ranges(
+    width: usize, height: usize, x: usize, y: usize, w: usize, h: usize
+) -> Result<(Range<usize>, Range<usize>), LifeBoardError> {
+    match (x.checked_add(w), y.checked_add(h)) {
+        (Some(x_end), Some(y_end)) if x_end <= width && y_end <= height => Ok((x..x_end, y..y_end)),
+        _ => Err(LifeBoardError::InvalidIndex(
+            format!("Region {w}x{h} at ({x}, {y}) is outside of the {width}x{height} board.")
+        )),
+    }
+}
+
+// Maps a coordinate that may be off the board back onto it along a wrapping axis, or drops it along a bounded one.
+pub(crate) fn wrapped_coordinate(coordinate: i64, len: usize, wrap: bool) -> Option<usize> {
+    if wrap {
+        Some(coordinate.rem_euclid(len as i64) as usize)
+    } else {
+        usize::try_from(coordinate).ok().filter(|&coordinate| coordinate < len)
+    }
+}
+
+pub(crate) fn hash_board_state<F: Fn(usize, usize) -> bool, H: Hasher>(width: usize, height: usize, is_alive: F, state: &mut H) {
+    state.write_usize(width);
+    state.write_usize(height);
+    for x in 0..width {
+        for y in 0..height {
+            state.write_u8(is_alive(x, y) as u8);
+        }
+    }
+}
+
+// `x` is the column and `y` the row, matching the `(x, y)` arguments taken everywhere else.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+} impl Point {
+    pub fn new(x: usize, y: usize) -> Point { Point { x, y } }
+} impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Point { Point { x, y } }
+} impl From<Point> for (usize, usize) {
+    fn from(point: Point) -> (usize, usize) { (point.x, point.y) }
+} impl From<Point> for (i64, i64) {
+    fn from(point: Point) -> (i64, i64) { (point.x as i64, point.y as i64) }
+} impl TryFrom<(i64, i64)> for Point {
+    type Error = LifeBoardError;
+
+    fn try_from((x, y): (i64, i64)) -> Result<Point, LifeBoardError> {
+        match (usize::try_from(x), usize::try_from(y)) {
+            (Ok(x), Ok(y)) => Ok(Point { x, y }),
+            _ => Err(LifeBoardError::InvalidIndex(format!("Cell ({x}, {y}) has a negative coordinate."))),
+        }
+    }
+}
+
+// One line per row with `separator` between the cells, as returned by `LifeBoard::fmt_with`.
+pub struct BoardFormatter<'a, T: LifeCell, B: LifeBoard<T>> {
+    board: &'a B,
+    alive: &'a str,
+    dead: &'a str,
+    separator: &'a str,
+    cell: PhantomData<T>,
+} impl<T: LifeCell, B: LifeBoard<T>> Display for BoardFormatter<'_, T, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_board(f, self.board.width(), self.board.height(), self.separator, false, |f, x, y|
+            f.write_str(if self.board.is_cell_alive(x, y) == Ok(true) { self.alive } else { self.dead })
+        )
+    }
+}
+
+// Writes one line per row with `separator` between the cells, and also after the last cell of every row when
+// `trailing_separator` is set.
+pub(crate) fn write_board<F>(f: &mut Formatter<'_>, width: usize, height: usize, separator: &str, trailing_separator: bool, mut write_cell: F) -> fmt::Result
+    where F: FnMut(&mut Formatter<'_>, usize, usize) -> fmt::Result
+{
+    for y in 0..height {
+        if y > 0 {
+            writeln!(f)?;
+        }
+        for x in 0..width {
+            if x > 0 {
+                f.write_str(separator)?;
+            }
+            write_cell(f, x, y)?;
+        }
+        if trailing_separator && width > 0 {
+            f.write_str(separator)?;
+        }
+    }
+    Ok(())
+}
+
+
+pub trait LifeCell: PartialEq + Clone {
     fn is_alive(&self) -> bool;
-    fn to_alive(&self) -> T;
-    fn to_dead(&self) -> T;
+    fn to_alive(&self) -> Self;
+    fn to_dead(&self) -> Self;
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CellEvent {
+    Born(usize, usize),
+    Died(usize, usize),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StepStats {
+    pub births: usize,
+    pub deaths: usize,
+    pub survivals: usize,
+} impl StepStats {
+    pub(crate) fn record(&mut self, was_alive: bool, is_alive: bool) {
+        match (was_alive, is_alive) {
+            (false, true) => self.births += 1,
+            (true, false) => self.deaths += 1,
+            (true, true) => self.survivals += 1,
+            (false, false) => (),
+        }
+    }
+
+    pub(crate) fn merge(&mut self, other: StepStats) {
+        self.births += other.births;
+        self.deaths += other.deaths;
+        self.survivals += other.survivals;
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PopulationStatistics {
+    pub alive: usize,
+    pub dead: usize,
+    pub density: f64,
+    pub births_this_gen: usize,
+    pub deaths_this_gen: usize,
+    pub net_change: i64,
+} impl PopulationStatistics {
+    pub(crate) fn from_step(n_cells: usize, alive: usize, stats: StepStats) -> PopulationStatistics {
+        PopulationStatistics {
+            alive,
+            dead: n_cells - alive,
+            density: if n_cells == 0 { 0.0 } else { alive as f64 / n_cells as f64 },
+            births_this_gen: stats.births,
+            deaths_this_gen: stats.deaths,
+            net_change: stats.births as i64 - stats.deaths as i64,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LifeBoardError {
     InvalidBoard(String),
     InvalidIndex(String),
+    Io(String),
+    Decode(String),
+    /// A worker thread panicked while simulating, leaving the board at its previous generation.
+    WorkerPanic(String),
+    /// The pattern or session asks for a rule other than B3/S23.
+    UnsupportedRule(String),
+} impl Display for LifeBoardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            LifeBoardError::InvalidBoard(message) => write!(f, "Invalid board: {message}"),
+            LifeBoardError::InvalidIndex(message) => write!(f, "Invalid index: {message}"),
+            LifeBoardError::Io(message) => write!(f, "I/O error: {message}"),
+            LifeBoardError::Decode(message) => write!(f, "Decode error: {message}"),
+            LifeBoardError::WorkerPanic(message) => write!(f, "Worker panic: {message}"),
+            LifeBoardError::UnsupportedRule(message) => write!(f, "Unsupported rule: {message}"),
+        }
+    }
+} impl std::error::Error for LifeBoardError {}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use crate::life_interface::LifeBoardError;
+
+    // Matching every variant makes adding one without a message a compile error.
+    fn display_prefix(error: &LifeBoardError) -> &'static str {
+        match error {
+            LifeBoardError::InvalidBoard(_) => "Invalid board: ",
+            LifeBoardError::InvalidIndex(_) => "Invalid index: ",
+            LifeBoardError::Io(_) => "I/O error: ",
+            LifeBoardError::Decode(_) => "Decode error: ",
+            LifeBoardError::WorkerPanic(_) => "Worker panic: ",
+            LifeBoardError::UnsupportedRule(_) => "Unsupported rule: ",
+        }
+    }
+
+    #[test]
+    fn test_equivalence_error_display_every_variant() {
+        let message = String::from("details.");
+        for error in [
+            LifeBoardError::InvalidBoard(message.clone()),
+            LifeBoardError::InvalidIndex(message.clone()),
+            LifeBoardError::Io(message.clone()),
+            LifeBoardError::Decode(message.clone()),
+            LifeBoardError::WorkerPanic(message.clone()),
+            LifeBoardError::UnsupportedRule(message.clone()),
+        ] {
+            assert_eq!(format!("{}{message}", display_prefix(&error)), error.to_string());
+        }
+    }
+
+    #[test]
+    fn test_equivalence_error_boxes_as_std_error() {
+        let parse = || -> Result<(), Box<dyn Error>> { Err(LifeBoardError::UnsupportedRule(String::from("B36/S23.")))? };
+        assert_eq!("Unsupported rule: B36/S23.", parse().unwrap_err().to_string());
+    }
 }
@@ -0,0 +1,110 @@
+use std::ops::Range;
+
+// A region quadtree over a square of `size` x `size` points (rounded up to a power of two), where every node keeps
+// the number of points beneath it so that counting the points in a rectangle can skip empty and fully covered nodes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QuadTree {
+    width: usize,
+    height: usize,
+    size: usize,
+    root: Node,
+} impl QuadTree {
+    pub fn new(width: usize, height: usize) -> QuadTree {
+        QuadTree { width, height, size: width.max(height).max(1).next_power_of_two(), root: Node::default() }
+    }
+
+    // Returns false if the point is out of bounds or already present.
+    pub fn insert(&mut self, x: usize, y: usize) -> bool {
+        if x >= self.width || y >= self.height || self.contains(x, y) {
+            return false
+        }
+        self.root.insert(x, y, self.size);
+        true
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.count_in(x..x + 1, y..y + 1) == 1
+    }
+
+    pub fn len(&self) -> usize { self.root.count }
+
+    pub fn is_empty(&self) -> bool { self.root.count == 0 }
+
+    // The number of points with `x` in `x_range` and `y` in `y_range`.
+    pub fn count_in(&self, x_range: Range<usize>, y_range: Range<usize>) -> usize {
+        self.root.count_in(0, 0, self.size, &x_range, &y_range)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Node {
+    count: usize,
+    /// The quadrants in the order top left, bottom left, top right, bottom right; `None` until a point lands inside.
+    children: Option<Box<[Node; 4]>>,
+} impl Node {
+    fn insert(&mut self, x: usize, y: usize, size: usize) {
+        self.count += 1;
+        if size == 1 {
+            return
+        }
+        let half = size / 2;
+        let quadrant = 2 * (x >= half) as usize + (y >= half) as usize;
+        let children = self.children.get_or_insert_with(Default::default);
+        children[quadrant].insert(x % half, y % half, half);
+    }
+
+    fn count_in(&self, left: usize, top: usize, size: usize, x_range: &Range<usize>, y_range: &Range<usize>) -> usize {
+        let (right, bottom) = (left + size, top + size);
+        if self.count == 0 || x_range.end <= left || right <= x_range.start || y_range.end <= top || bottom <= y_range.start {
+            0
+        } else if x_range.start <= left && right <= x_range.end && y_range.start <= top && bottom <= y_range.end {
+            self.count
+        } else {
+            let half = size / 2;
+            self.children.as_ref().map_or(0, |children|
+                children.iter().enumerate().map(|(quadrant, child)|
+                    child.count_in(left + half * (quadrant / 2), top + half * (quadrant % 2), half, x_range, y_range)
+                ).sum()
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life_quadtree::QuadTree;
+
+    #[test]
+    fn test_equivalence_quadtree_insert_and_contains() {
+        let mut tree = QuadTree::new(10, 6);
+        assert!(tree.insert(3, 5));
+        assert!(tree.insert(9, 0));
+        assert!(!tree.insert(3, 5));
+        assert_eq!(2, tree.len());
+        assert!(tree.contains(3, 5) && tree.contains(9, 0));
+        assert!(!tree.contains(5, 3));
+    }
+
+    #[test]
+    fn test_equivalence_quadtree_count_in_matches_brute_force() {
+        let points = [(0, 0), (1, 2), (2, 2), (7, 7), (4, 1), (5, 6), (3, 3)];
+        let mut tree = QuadTree::new(8, 8);
+        for (x, y) in points {
+            tree.insert(x, y);
+        }
+        for (x_range, y_range) in [(0..8, 0..8), (1..3, 1..4), (3..4, 3..4), (4..8, 0..2), (6..8, 6..8), (2..2, 0..8)] {
+            let expected = points.iter().filter(|(x, y)| x_range.contains(x) && y_range.contains(y)).count();
+            assert_eq!(expected, tree.count_in(x_range.clone(), y_range.clone()), "{x_range:?} {y_range:?}");
+        }
+    }
+
+    #[test]
+    fn test_boundary_quadtree_insert_out_of_bounds() {
+        let mut tree = QuadTree::new(3, 3);
+        assert!(!tree.insert(3, 0));
+        assert!(!tree.insert(0, 3));
+        assert!(tree.insert(2, 2));
+        assert_eq!(1, tree.len());
+        assert!(!tree.contains(3, 3));
+    }
+}
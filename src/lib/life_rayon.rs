@@ -0,0 +1,91 @@
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use crate::life_implementation::BaseLifeBoard;
+use crate::life_interface::{LifeBoard, LifeBoardError, LifeCell, StepStats};
+
+// Steps the wrapped board with `BaseLifeBoard::simulate_rayon`, leaving the thread pool and load balancing to rayon
+// instead of the fixed ranges and worker threads of `ParallelLifeBoard`.
+#[derive(Clone, PartialEq)]
+pub struct RayonLifeBoard<T: LifeCell + Send + Sync> {
+    board: BaseLifeBoard<T>,
+} impl<T: LifeCell + Send + Sync> RayonLifeBoard<T> {
+    pub fn from_board(board: BaseLifeBoard<T>) -> RayonLifeBoard<T> { RayonLifeBoard { board } }
+
+    pub fn into_board(self) -> BaseLifeBoard<T> { self.board }
+} impl<T: LifeCell + Send + Sync> LifeBoard<T> for RayonLifeBoard<T> {
+    fn width(&self) -> usize { self.board.width() }
+
+    fn height(&self) -> usize { self.board.height() }
+
+    fn simulate(&mut self) {
+        self.simulate_stats();
+    }
+
+    fn simulate_stats(&mut self) -> StepStats { self.board.simulate_rayon() }
+
+    fn next_cell_state_at(&self, x: usize, y: usize) -> Result<T, LifeBoardError> { self.board.next_cell_state_at(x, y) }
+
+    fn cell_at(&self, x: usize, y: usize) -> Result<&T, LifeBoardError> { self.board.cell_at(x, y) }
+
+    fn num_alive_neighbors_at(&self, x: usize, y: usize) -> Result<u8, LifeBoardError> { self.board.num_alive_neighbors_at(x, y) }
+
+    fn is_cell_alive(&self, x: usize, y: usize) -> Result<bool, LifeBoardError> { self.board.is_cell_alive(x, y) }
+
+    fn to_vec_matrix(&self) -> Vec<Vec<T>> { self.board.to_vec_matrix() }
+
+    fn fill_region(&mut self, x: usize, y: usize, w: usize, h: usize, alive: bool) -> Result<(), LifeBoardError> {
+        self.board.fill_region(x, y, w, h, alive)
+    }
+
+    fn count_alive(&self) -> usize { self.board.count_alive() }
+
+    fn is_stable(&self) -> bool { self.board.is_stable() }
+} impl<T: LifeCell + Send + Sync> Display for RayonLifeBoard<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.board, f)
+    }
+} impl<T: LifeCell + Send + Sync> Debug for RayonLifeBoard<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.board, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life_implementation::{BaseLifeBoard, Cell};
+    use crate::life_interface::LifeBoard;
+    use crate::life_rayon::RayonLifeBoard;
+    use crate::patterns;
+    use crate::test_fixtures::{get_7x7_end_board_10th_gen, get_7x7_start_board_0th_gen};
+
+    #[test]
+    fn test_equivalence_rayon_board_simulate_7x7_board_10_steps() {
+        let mut board = RayonLifeBoard::from_board(get_7x7_start_board_0th_gen());
+        board.simulate_n_steps(10);
+        assert_eq!(get_7x7_end_board_10th_gen(), board.into_board());
+    }
+
+    #[test]
+    fn test_equivalence_rayon_board_matches_base_board() {
+        for (width, height) in [(1, 1), (1, 9), (9, 1), (23, 17)] {
+            let mut base_board = BaseLifeBoard::gen(width, height, Cell::gen);
+            let mut rayon_board = RayonLifeBoard::from_board(base_board.clone());
+            for _ in 0..15 {
+                assert_eq!(base_board.simulate_stats(), rayon_board.simulate_stats());
+            }
+            assert_eq!(base_board, rayon_board.into_board());
+        }
+    }
+
+    #[test]
+    fn test_equivalence_rayon_board_auto_expand_glider_40_steps() {
+        let mut base_board = patterns::glider(Cell::from_bool);
+        base_board.set_auto_expand(Some(2));
+        let mut rayon_board = RayonLifeBoard::from_board(base_board.clone());
+        for _ in 0..40 {
+            assert_eq!(base_board.simulate_stats(), rayon_board.simulate_stats());
+        }
+        assert_eq!((17, 17, 5), (rayon_board.width(), rayon_board.height(), rayon_board.count_alive()));
+        assert_eq!(base_board, rayon_board.into_board());
+    }
+}
@@ -0,0 +1,182 @@
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::life_implementation::{BaseLifeBoard, Cell};
+use crate::life_interface::{LifeBoard, LifeBoardError};
+
+const REPLAY_MAGIC: &[u8; 4] = b"LRPL";
+const REPLAY_VERSION: u8 = 1;
+const REPLAY_RULE: &str = "B3/S23";
+
+// Every generation of a run, starting with the initial board, so it can be played back frame by frame.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Replay {
+    pub rule: String,
+    pub width: usize,
+    pub height: usize,
+    /// Seconds since the Unix epoch at which the replay was recorded.
+    pub created_at: u64,
+    frames: Vec<BaseLifeBoard<Cell>>,
+} impl Replay {
+    pub fn record(board: &mut impl LifeBoard<Cell>, steps: usize) -> Replay {
+        let mut frames = Vec::with_capacity(steps + 1);
+        frames.push(Replay::_frame(board));
+        for _ in 0..steps {
+            board.simulate();
+            frames.push(Replay::_frame(board));
+        }
+        Replay {
+            rule: String::from(REPLAY_RULE),
+            width: board.width(),
+            height: board.height(),
+            created_at: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs()),
+            frames,
+        }
+    }
+
+    fn _frame(board: &impl LifeBoard<Cell>) -> BaseLifeBoard<Cell> {
+        BaseLifeBoard::from_cell_matrix(board.to_vec_matrix()).expect("Should always be a valid board")
+    }
+
+    pub fn play_at(&self, step: usize) -> Option<&BaseLifeBoard<Cell>> { self.frames.get(step) }
+
+    pub fn frames(&self) -> &[BaseLifeBoard<Cell>] { &self.frames }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), LifeBoardError> {
+        let path = path.as_ref();
+        fs::write(path, self.to_bytes())
+            .map_err(|error| LifeBoardError::Io(format!("Could not write replay {}: {error}", path.display())))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Replay, LifeBoardError> {
+        let path = path.as_ref();
+        let bytes = fs::read(path)
+            .map_err(|error| LifeBoardError::Io(format!("Could not read replay {}: {error}", path.display())))?;
+        Replay::from_bytes(&bytes)
+    }
+
+    // Layout: magic, version, created_at, width, height, rule length and bytes, frame count, then each frame's length and
+    // `BaseLifeBoard::to_bytes` snapshot. Integers are little-endian u64 except the u8 version and u32 rule length.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(REPLAY_MAGIC);
+        bytes.push(REPLAY_VERSION);
+        bytes.extend_from_slice(&self.created_at.to_le_bytes());
+        bytes.extend_from_slice(&(self.width as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.height as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.rule.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(self.rule.as_bytes());
+        bytes.extend_from_slice(&(self.frames.len() as u64).to_le_bytes());
+        for frame in &self.frames {
+            let frame_bytes = frame.to_bytes();
+            bytes.extend_from_slice(&(frame_bytes.len() as u64).to_le_bytes());
+            bytes.extend(frame_bytes);
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Replay, LifeBoardError> {
+        let mut reader = ReplayReader { rest: bytes };
+        if reader.take(4)? != REPLAY_MAGIC {
+            return Err(invalid_replay("unrecognized magic bytes"));
+        }
+        let version = reader.take(1)?[0];
+        if version != REPLAY_VERSION {
+            return Err(invalid_replay(&format!("unsupported version {version}")));
+        }
+        let created_at = reader.take_u64()?;
+        let (width, height) = (reader.take_usize()?, reader.take_usize()?);
+        let rule_len = u32::from_le_bytes(reader.take(4)?.try_into().expect("Should always be 4 bytes")) as usize;
+        let rule = String::from_utf8(reader.take(rule_len)?.to_vec()).map_err(|_| invalid_replay("rule is not UTF-8"))?;
+        let n_frames = reader.take_u64()?;
+        let mut frames = Vec::new();
+        for frame_idx in 0..n_frames {
+            let frame_len = reader.take_usize()?;
+            let frame = BaseLifeBoard::from_bytes(reader.take(frame_len)?, Cell::from_bool)
+                .map_err(|error| invalid_replay(&format!("frame {frame_idx} is not a valid board ({error:?})")))?;
+            if (frame.width(), frame.height()) != (width, height) {
+                return Err(invalid_replay(&format!(
+                    "frame {frame_idx} is {}x{} but the replay is {width}x{height}", frame.width(), frame.height()
+                )));
+            }
+            frames.push(frame);
+        }
+        if !reader.rest.is_empty() {
+            return Err(invalid_replay("unexpected trailing data"));
+        }
+        Ok(Replay { rule, width, height, created_at, frames })
+    }
+}
+
+fn invalid_replay(reason: &str) -> LifeBoardError {
+    LifeBoardError::Decode(format!("Invalid replay: {reason}."))
+}
+
+struct ReplayReader<'a> {
+    rest: &'a [u8],
+} impl<'a> ReplayReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], LifeBoardError> {
+        if self.rest.len() < len {
+            return Err(invalid_replay("truncated data"));
+        }
+        let (taken, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        Ok(taken)
+    }
+
+    fn take_u64(&mut self) -> Result<u64, LifeBoardError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().expect("Should always be 8 bytes")))
+    }
+
+    fn take_usize(&mut self) -> Result<usize, LifeBoardError> {
+        usize::try_from(self.take_u64()?).map_err(|_| invalid_replay("value is too large"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::life_implementation::{Cell, ParallelLifeBoard};
+    use crate::life_interface::LifeBoardError;
+    use crate::life_replay::Replay;
+    use crate::patterns;
+
+    fn get_glider_board() -> ParallelLifeBoard<Cell> {
+        let mut board = patterns::glider(Cell::from_bool);
+        board.resize(8, 8, false).unwrap();
+        board.translate(1, 4, false);
+        ParallelLifeBoard::from_board(board, 2)
+    }
+
+    #[test]
+    fn test_equivalence_replay_record_save_load() {
+        let mut board = get_glider_board();
+        let replay = Replay::record(&mut board, 4);
+        assert_eq!(5, replay.frames().len());
+        assert_eq!(&board.clone().into_board(), replay.play_at(4).unwrap());
+        assert_eq!(&get_glider_board().into_board(), replay.play_at(0).unwrap());
+        assert_eq!(None, replay.play_at(5));
+        let path = std::env::temp_dir().join(format!("life_replay_{}.bin", std::process::id()));
+        replay.save(&path).unwrap();
+        let loaded = Replay::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(replay, loaded);
+        assert_eq!(("B3/S23", 8, 8), (loaded.rule.as_str(), loaded.width, loaded.height));
+    }
+
+    #[test]
+    fn test_exception_replay_corrupted_bytes() {
+        let bytes = Replay::record(&mut get_glider_board(), 2).to_bytes();
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        for (bytes, expected) in [(&bytes[..bytes.len() - 1], "truncated"), (&bad_magic[..], "magic"), (&trailing[..], "trailing")] {
+            match Replay::from_bytes(bytes) {
+                Err(LifeBoardError::Decode(error)) => assert!(error.contains(expected), "{error}"),
+                result => panic!("Expected a Decode error but found {result:?}"),
+            }
+        }
+        assert!(matches!(Replay::load("does/not/exist.bin"), Err(LifeBoardError::Io(_))), "Missing files should fail");
+    }
+}
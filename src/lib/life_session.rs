@@ -0,0 +1,136 @@
+use std::path::Path;
+use crate::life_builder::Boundary;
+use crate::life_implementation::{BaseLifeBoard, Cell, ParallelLifeBoard};
+use crate::life_interface::LifeBoardError;
+
+const DEFAULT_RULE: &str = "B3/S23";
+
+fn default_rule() -> String { String::from(DEFAULT_RULE) }
+
+fn default_n_threads() -> u8 { 1 }
+
+// Every field but the board falls back to its default so sessions saved by older versions still load. `boundary`
+// follows the wrapping of `board` when a session is created and overrides it when one is loaded.
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Session {
+    pub board: BaseLifeBoard<Cell>,
+    #[serde(default)]
+    pub generation: u64,
+    #[serde(default = "default_rule")]
+    pub rule: String,
+    #[serde(default)]
+    pub boundary: Boundary,
+    #[serde(default = "default_n_threads")]
+    pub n_threads: u8,
+} impl Session {
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), LifeBoardError> {
+        let path = path.as_ref();
+        let json = serde_json::to_string(self)
+            .map_err(|error| LifeBoardError::Decode(format!("Could not encode session: {error}")))?;
+        std::fs::write(path, json)
+            .map_err(|error| LifeBoardError::Io(format!("Could not write session {}: {error}", path.display())))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Session, LifeBoardError> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .map_err(|error| LifeBoardError::Io(format!("Could not read session {}: {error}", path.display())))?;
+        Session::from_json(&json)
+    }
+
+    pub fn from_json(json: &str) -> Result<Session, LifeBoardError> {
+        let mut session: Session = serde_json::from_str(json)
+            .map_err(|error| LifeBoardError::Decode(format!("Could not decode session: {error}")))?;
+        if !matches!(session.rule.as_str(), "B3/S23" | "23/3") {
+            return Err(LifeBoardError::UnsupportedRule(format!("Session rule \"{}\" is not B3/S23.", session.rule)));
+        }
+        if session.n_threads == 0 {
+            return Err(LifeBoardError::InvalidBoard(String::from("Session must use at least one thread.")));
+        }
+        let (wrap_x, wrap_y) = session.boundary.wrap();
+        session.board.set_wrap(wrap_x, wrap_y);
+        Ok(session)
+    }
+} impl From<BaseLifeBoard<Cell>> for Session {
+    fn from(board: BaseLifeBoard<Cell>) -> Self {
+        let boundary = Boundary::from_wrap(board.wrap_x(), board.wrap_y());
+        Session { board, generation: 0, rule: default_rule(), boundary, n_threads: default_n_threads() }
+    }
+} impl From<ParallelLifeBoard<Cell>> for Session {
+    fn from(board: ParallelLifeBoard<Cell>) -> Self {
+        let n_threads = board.n_threads();
+        Session { n_threads, ..Session::from(board.into_board()) }
+    }
+} impl From<Session> for BaseLifeBoard<Cell> {
+    fn from(session: Session) -> Self { session.board }
+} impl From<Session> for ParallelLifeBoard<Cell> {
+    fn from(session: Session) -> Self { ParallelLifeBoard::from_board(session.board, session.n_threads) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life_builder::Boundary;
+    use crate::life_implementation::{BaseLifeBoard, Cell, Neighborhood, ParallelLifeBoard};
+    use crate::life_interface::{LifeBoard, LifeBoardError};
+    use crate::life_session::Session;
+
+    #[test]
+    fn test_equivalence_session_save_load_round_trip() {
+        let mut board = BaseLifeBoard::gen(20, 10, Cell::gen);
+        board.set_neighborhood(Neighborhood::VonNeumann).unwrap();
+        let session = Session { generation: 42, ..Session::from(ParallelLifeBoard::from_board(board, 3)) };
+        let path = std::env::temp_dir().join(format!("life_session_{}.json", std::process::id()));
+        session.save(&path).unwrap();
+        let loaded = Session::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(session, loaded);
+        let board = ParallelLifeBoard::from(loaded);
+        assert_eq!((3, Neighborhood::VonNeumann), (board.n_threads(), board.neighborhood()));
+    }
+
+    #[test]
+    fn test_equivalence_session_missing_fields_use_defaults() {
+        let board = BaseLifeBoard::from_bool_matrix([[true, false], [false, true]], Cell::from_bool).unwrap();
+        let json = format!("{{\"board\": {}}}", serde_json::to_string(&board).unwrap());
+        let session = Session::from_json(&json).unwrap();
+        assert_eq!(Session::from(board.clone()), session);
+        assert_eq!((0, "B3/S23", Boundary::Dead, 1), (session.generation, session.rule.as_str(), session.boundary, session.n_threads));
+        assert_eq!(2, BaseLifeBoard::from(session).count_alive());
+    }
+
+    #[test]
+    fn test_equivalence_session_boundary_follows_board_wrap() {
+        let mut board = BaseLifeBoard::gen(4, 4, Cell::gen);
+        board.set_wrap(true, true);
+        let session = Session::from(ParallelLifeBoard::from_board(board.clone(), 2));
+        assert_eq!(Boundary::Torus, session.boundary);
+        let json = serde_json::to_string(&session).unwrap();
+        assert!(json.contains("\"boundary\":\"toroidal\""), "{json}");
+        assert_eq!(session, Session::from_json(&json).unwrap());
+        board.set_wrap(false, false);
+        for (boundary, wrap) in [("toroidal", (true, true)), ("wrap_x", (true, false)), ("wrap_y", (false, true)), ("dead", (false, false))] {
+            let json = format!("{{\"board\": {}, \"boundary\": \"{boundary}\"}}", serde_json::to_string(&board).unwrap());
+            let board = BaseLifeBoard::from(Session::from_json(&json).unwrap());
+            assert_eq!(wrap, (board.wrap_x(), board.wrap_y()), "{boundary}");
+        }
+    }
+
+    #[test]
+    fn test_exception_session_invalid_settings() {
+        let board = serde_json::to_string(&BaseLifeBoard::gen(3, 3, Cell::gen)).unwrap();
+        match Session::from_json(&format!("{{\"board\": {board}, \"n_threads\": 0}}")) {
+            Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("thread"), "{error}"),
+            result => panic!("Expected an InvalidBoard error but found {result:?}"),
+        }
+        match Session::from_json(&format!("{{\"board\": {board}, \"boundary\": \"klein\"}}")) {
+            Err(LifeBoardError::Decode(error)) => assert!(error.contains("klein"), "{error}"),
+            result => panic!("Expected a Decode error but found {result:?}"),
+        }
+        match Session::from_json(&format!("{{\"board\": {board}, \"rule\": \"B36/S23\"}}")) {
+            Err(LifeBoardError::UnsupportedRule(error)) => assert!(error.contains("B36/S23"), "{error}"),
+            result => panic!("Expected an UnsupportedRule error but found {result:?}"),
+        }
+        assert!(matches!(Session::from_json("{}"), Err(LifeBoardError::Decode(_))), "A session needs a board");
+        assert!(matches!(Session::load("does/not/exist.json"), Err(LifeBoardError::Io(_))), "Missing files should fail");
+    }
+}
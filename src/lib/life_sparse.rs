@@ -0,0 +1,148 @@
+use std::collections::{HashMap, HashSet};
+use crate::life_implementation::BaseLifeBoard;
+use crate::life_interface::{LifeBoard, LifeCell};
+
+// An unbounded B3/S23 universe that only stores live cells, so growing patterns are never clipped.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SparseLifeBoard {
+    live_cells: HashSet<(i64, i64)>,
+} impl SparseLifeBoard {
+    pub fn new() -> SparseLifeBoard { SparseLifeBoard::default() }
+
+    pub fn from_cells<I: IntoIterator<Item=(i64, i64)>>(cells: I) -> SparseLifeBoard {
+        SparseLifeBoard { live_cells: cells.into_iter().collect() }
+    }
+
+    // Places the board's top-left corner at the origin.
+    pub fn from_board<T: LifeCell>(board: &BaseLifeBoard<T>) -> SparseLifeBoard {
+        SparseLifeBoard::from_cells((0..board.width()).flat_map(|x|
+            (0..board.height()).filter(move |&y| board.is_cell_alive(x, y) == Ok(true)).map(move |y| (x as i64, y as i64))
+        ))
+    }
+
+    pub fn live_cells(&self) -> &HashSet<(i64, i64)> { &self.live_cells }
+
+    pub fn population(&self) -> usize { self.live_cells.len() }
+
+    pub fn is_alive(&self, x: i64, y: i64) -> bool { self.live_cells.contains(&(x, y)) }
+
+    pub fn set_alive(&mut self, x: i64, y: i64, alive: bool) {
+        if alive {
+            self.live_cells.insert((x, y));
+        } else {
+            self.live_cells.remove(&(x, y));
+        }
+    }
+
+    pub fn num_alive_neighbors_at(&self, x: i64, y: i64) -> u8 {
+        let mut neighbors = 0;
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                if (dx != 0 || dy != 0) && self.is_alive(x + dx, y + dy) {
+                    neighbors += 1;
+                }
+            }
+        }
+        neighbors
+    }
+
+    // Returns the inclusive `((min_x, min_y), (max_x, max_y))` corners of the live cells.
+    pub fn bounds(&self) -> Option<((i64, i64), (i64, i64))> {
+        let min_x = self.live_cells.iter().map(|&(x, _)| x).min()?;
+        let max_x = self.live_cells.iter().map(|&(x, _)| x).max()?;
+        let min_y = self.live_cells.iter().map(|&(_, y)| y).min()?;
+        let max_y = self.live_cells.iter().map(|&(_, y)| y).max()?;
+        Some(((min_x, min_y), (max_x, max_y)))
+    }
+
+    pub fn simulate(&mut self) {
+        let mut neighbor_counts: HashMap<(i64, i64), u8> = HashMap::with_capacity(self.live_cells.len() * 8);
+        for &(x, y) in &self.live_cells {
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    if dx != 0 || dy != 0 {
+                        *neighbor_counts.entry((x + dx, y + dy)).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+        self.live_cells = neighbor_counts.into_iter()
+            .filter(|&(cell, count)| count == 3 || (count == 2 && self.live_cells.contains(&cell)))
+            .map(|(cell, _)| cell)
+            .collect();
+    }
+
+    pub fn simulate_n_steps(&mut self, n: usize) {
+        for _ in 0..n {
+            self.simulate();
+        }
+    }
+
+    // Crops to the bounding box of the live cells, or a 1x1 dead board when there are none.
+    pub fn to_board<T: LifeCell>(&self, init: fn(state: bool) -> T) -> BaseLifeBoard<T> {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds().unwrap_or(((0, 0), (0, 0)));
+        let grid = (min_x..=max_x).map(|x| (min_y..=max_y).map(|y| self.is_alive(x, y)).collect::<Vec<bool>>()).collect::<Vec<_>>();
+        BaseLifeBoard::from_bool_matrix(grid, init).expect("Should always be a non-empty rectangle")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::life_implementation::{BaseLifeBoard, Cell};
+    use crate::life_interface::LifeBoard;
+    use crate::life_sparse::SparseLifeBoard;
+    use crate::patterns;
+
+    #[test]
+    fn test_equivalence_sparse_matches_base_away_from_edges() {
+        let mut base_board = patterns::toad(Cell::from_bool);
+        base_board.resize(8, 8, false).unwrap();
+        base_board.translate(3, 2, false);
+        let mut sparse_board = SparseLifeBoard::from_board(&base_board);
+        for _ in 0..4 {
+            base_board.simulate();
+            sparse_board.simulate();
+            assert_eq!(SparseLifeBoard::from_board(&base_board), sparse_board);
+        }
+    }
+
+    #[test]
+    fn test_equivalence_sparse_glider_translates_forever() {
+        let glider = patterns::glider(Cell::from_bool);
+        let mut board = SparseLifeBoard::from_board(&glider);
+        board.simulate_n_steps(400);
+        assert_eq!(5, board.population());
+        let moved = SparseLifeBoard::from_cells(board.live_cells().iter().map(|&(x, y)| (x - 100, y + 100)));
+        assert_eq!(SparseLifeBoard::from_board(&glider), moved);
+        assert_eq!(glider, board.to_board(Cell::from_bool));
+    }
+
+    #[test]
+    fn test_equivalence_sparse_glider_at_negative_coordinates_4_steps() {
+        let glider = [(-9, -10), (-8, -9), (-10, -8), (-9, -8), (-8, -8)];
+        let mut board = SparseLifeBoard::from_cells(glider);
+        assert_eq!((5, 1), (board.num_alive_neighbors_at(-9, -9), board.num_alive_neighbors_at(-10, -10)));
+        board.simulate_n_steps(4);
+        assert_eq!(SparseLifeBoard::from_cells(glider.map(|(x, y)| (x + 1, y + 1))), board);
+    }
+
+    #[test]
+    fn test_boundary_sparse_gosper_gun_is_not_clipped() {
+        let gun = patterns::gosper_glider_gun(Cell::from_bool);
+        let mut board = SparseLifeBoard::from_board(&gun);
+        board.simulate_n_steps(50);
+        let ((min_x, min_y), (max_x, max_y)) = board.bounds().unwrap();
+        assert!(max_y >= gun.height() as i64 + 2, "Expected a glider below the gun: {:?}", board.bounds());
+        assert!(min_x >= 0 && min_y >= 0 && max_x < gun.width() as i64, "{:?}", board.bounds());
+        let outside_cells = board.live_cells().iter().filter(|&&(_, y)| y >= gun.height() as i64).count();
+        assert!(outside_cells >= 5, "Expected at least one whole glider outside of the gun's bounds");
+    }
+
+    #[test]
+    fn test_boundary_sparse_empty_board() {
+        let mut board = SparseLifeBoard::new();
+        board.simulate();
+        assert_eq!(None, board.bounds());
+        assert_eq!(BaseLifeBoard::from_bool_matrix([[false]], Cell::from_bool).unwrap(), board.to_board(Cell::from_bool));
+    }
+}
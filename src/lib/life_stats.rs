@@ -0,0 +1,199 @@
+use std::fs;
+use std::marker::PhantomData;
+use std::path::Path;
+use crate::life_implementation::BaseLifeBoard;
+use crate::life_interface::{LifeBoard, LifeBoardError, LifeCell, PopulationStatistics, StepStats};
+
+const STATS_CSV_HEADER: &str = "generation,population,births,deaths,density";
+const POPULATION_CSV_HEADER: &str = "generation,population";
+
+// Steps any board while keeping one row of statistics per generation, starting with generation 0.
+pub struct StatsRecorder<B: LifeBoard<T>, T: LifeCell> {
+    board: B,
+    generation: u64,
+    recording: bool,
+    records: Vec<(u64, PopulationStatistics)>,
+    _cell: PhantomData<T>,
+} impl<B: LifeBoard<T>, T: LifeCell> StatsRecorder<B, T> {
+    pub fn new(board: B) -> StatsRecorder<B, T> {
+        let mut recorder = StatsRecorder { board, generation: 0, recording: true, records: Vec::new(), _cell: PhantomData };
+        recorder._record(StepStats::default());
+        recorder
+    }
+
+    pub fn board(&self) -> &B { &self.board }
+
+    // Edits made through here, such as `clear`, are not steps and leave the generation unchanged.
+    pub fn board_mut(&mut self) -> &mut B { &mut self.board }
+
+    pub fn into_board(self) -> B { self.board }
+
+    pub fn generation(&self) -> u64 { self.generation }
+
+    pub fn records(&self) -> &[(u64, PopulationStatistics)] { &self.records }
+
+    pub fn is_recording(&self) -> bool { self.recording }
+
+    // While paused, steps fall through to a plain `simulate` and nothing is recorded.
+    pub fn set_recording(&mut self, recording: bool) { self.recording = recording; }
+
+    pub fn step(&mut self) {
+        self.generation += 1;
+        if self.recording {
+            let stats = self.board.simulate_stats();
+            self._record(stats);
+        } else {
+            self.board.simulate();
+        }
+    }
+
+    pub fn step_n(&mut self, n: usize) {
+        for _ in 0..n {
+            self.step();
+        }
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{STATS_CSV_HEADER}\n");
+        for (generation, stats) in &self.records {
+            csv.push_str(&format!(
+                "{generation},{},{},{},{}\n", stats.alive, stats.births_this_gen, stats.deaths_this_gen, stats.density
+            ));
+        }
+        csv
+    }
+
+    pub fn write_csv<P: AsRef<Path>>(&self, path: P) -> Result<(), LifeBoardError> {
+        let path = path.as_ref();
+        fs::write(path, self.to_csv())
+            .map_err(|error| LifeBoardError::Io(format!("Could not write statistics {}: {error}", path.display())))
+    }
+
+    fn _record(&mut self, stats: StepStats) {
+        let n_cells = self.board.width() * self.board.height();
+        let stats = PopulationStatistics::from_step(n_cells, self.board.count_alive(), stats);
+        self.records.push((self.generation, stats));
+    }
+}
+
+impl<T: LifeCell> BaseLifeBoard<T> {
+    // The population at generation 0 and after each of `steps` steps, simulated on a clone.
+    pub fn population_series(&self, steps: usize) -> Vec<usize> { self.clone().simulate_with_history(steps) }
+}
+
+// Writes one `generation,population` row per entry of `series`, numbering generations from 0.
+pub fn write_population_csv<P: AsRef<Path>>(path: P, series: &[usize]) -> Result<(), LifeBoardError> {
+    let path = path.as_ref();
+    let mut csv = format!("{POPULATION_CSV_HEADER}\n");
+    for (generation, population) in series.iter().enumerate() {
+        csv.push_str(&format!("{generation},{population}\n"));
+    }
+    fs::write(path, csv)
+        .map_err(|error| LifeBoardError::Io(format!("Could not write population series {}: {error}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::life_bitboard::BitBoard;
+    use crate::life_dead_zone::DeadZoneOptimizedBoard;
+    use crate::life_flat::FlatLifeBoard;
+    use crate::life_frontier::FrontierLifeBoard;
+    use crate::life_implementation::{BaseLifeBoard, Cell, ParallelLifeBoard};
+    use crate::life_interface::{LifeBoard, LifeCell};
+    use crate::life_stats::{self, StatsRecorder};
+    use crate::test_fixtures::get_7x7_start_board_0th_gen;
+
+    fn population_column(csv: &str) -> Vec<usize> {
+        csv.lines().skip(1).map(|line| line.split(',').nth(1).unwrap().parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_equivalence_stats_recorder_7x7_board_10_steps() {
+        let mut recorder = StatsRecorder::new(get_7x7_start_board_0th_gen());
+        recorder.step_n(10);
+        let path = std::env::temp_dir().join(format!("life_stats_{}.csv", std::process::id()));
+        recorder.write_csv(&path).unwrap();
+        let csv = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(Some("generation,population,births,deaths,density"), csv.lines().next());
+        assert_eq!(12, csv.lines().count());
+        let populations = population_column(&csv);
+        assert_eq!(11, populations.len());
+        assert_eq!((15, 14, 12), (populations[0], populations[1], populations[10]));
+        assert_eq!(recorder.board().count_alive(), populations[10]);
+    }
+
+    #[test]
+    fn test_equivalence_stats_recorder_parallel_matches_base() {
+        let mut base_recorder = StatsRecorder::new(get_7x7_start_board_0th_gen());
+        let mut parallel_recorder = StatsRecorder::new(ParallelLifeBoard::from_board(get_7x7_start_board_0th_gen(), 3));
+        base_recorder.step_n(10);
+        parallel_recorder.step_n(10);
+        assert_eq!(base_recorder.to_csv(), parallel_recorder.to_csv());
+    }
+
+    #[test]
+    fn test_boundary_stats_recorder_paused_skips_records() {
+        let mut recorder = StatsRecorder::new(get_7x7_start_board_0th_gen());
+        recorder.set_recording(false);
+        recorder.step_n(9);
+        recorder.set_recording(true);
+        recorder.step();
+        let generations: Vec<u64> = recorder.records().iter().map(|&(generation, _)| generation).collect();
+        assert_eq!(vec![0, 10], generations);
+        assert_eq!(vec![15, 12], population_column(&recorder.to_csv()));
+    }
+
+    fn assert_clear_keeps_generation<B: LifeBoard<T>, T: LifeCell>(board: B) {
+        let mut recorder = StatsRecorder::new(board);
+        recorder.step_n(3);
+        recorder.board_mut().fill_alive();
+        assert_eq!(49, recorder.board().count_alive());
+        recorder.board_mut().clear_region(1, 2, 3, 4).unwrap();
+        assert_eq!(37, recorder.board().count_alive());
+        assert!(recorder.board_mut().clear_region(5, 0, 3, 1).is_err(), "Regions past the edge should be rejected");
+        let mut expected_board = BaseLifeBoard::from_cell_matrix(recorder.board().to_vec_matrix()).unwrap();
+        expected_board.simulate();
+        recorder.step();
+        assert_eq!(expected_board.to_rle(), recorder.board().to_rle());
+        recorder.board_mut().clear();
+        assert_eq!((0, 4), (recorder.board().count_alive(), recorder.generation()));
+        recorder.step();
+        assert_eq!((0, 5), (recorder.board().count_alive(), recorder.generation()));
+    }
+
+    #[test]
+    fn test_equivalence_clear_keeps_generation_on_every_board() {
+        let board = get_7x7_start_board_0th_gen();
+        assert_clear_keeps_generation(board.clone());
+        assert_clear_keeps_generation(ParallelLifeBoard::from_board(board.clone(), 3));
+        assert_clear_keeps_generation(BitBoard::from_board(&board));
+        assert_clear_keeps_generation(FlatLifeBoard::from_board(&board));
+        assert_clear_keeps_generation(FrontierLifeBoard::from_board(board.clone()));
+        assert_clear_keeps_generation(DeadZoneOptimizedBoard::from_board(board));
+    }
+
+    #[test]
+    fn test_equivalence_population_series_7x7_board() {
+        let board = get_7x7_start_board_0th_gen();
+        let series = board.population_series(10);
+        assert_eq!(11, series.len());
+        assert_eq!((15, 14, 12), (series[0], series[1], series[10]));
+        assert_eq!(get_7x7_start_board_0th_gen(), board);
+        let path = std::env::temp_dir().join(format!("life_population_{}.csv", std::process::id()));
+        life_stats::write_population_csv(&path, &series).unwrap();
+        let csv = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(Some("generation,population"), csv.lines().next());
+        assert_eq!(Some("10,12"), csv.lines().last());
+        assert_eq!(series, population_column(&csv));
+    }
+
+    #[test]
+    fn test_boundary_population_series_dead_board() {
+        let board = BaseLifeBoard::from_bool_matrix(vec![vec![false; 6]; 5], Cell::from_bool).unwrap();
+        assert_eq!(vec![0; 8], board.population_series(7));
+        assert_eq!(vec![0], board.population_series(0));
+    }
+}
@@ -0,0 +1,80 @@
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+use wasm_bindgen::prelude::wasm_bindgen;
+use crate::life_implementation::{BaseLifeBoard, Cell};
+use crate::life_interface::LifeBoard;
+
+const ALIVE_RGBA: [u8; 4] = [0xff, 0xff, 0xff, 0xff];  // Alive cells are drawn opaque white
+const DEAD_RGBA: [u8; 4] = [0x00, 0x00, 0x00, 0xff];  // Dead cells are drawn opaque black
+
+// A single-threaded board for JavaScript, which draws it by blitting `to_rgba` into a canvas `ImageData`.
+#[wasm_bindgen]
+pub struct WasmLifeBoard {
+    board: BaseLifeBoard<Cell>,
+}
+
+#[wasm_bindgen]
+impl WasmLifeBoard {
+    #[wasm_bindgen(constructor)]
+    pub fn new(width: usize, height: usize, density: f64) -> WasmLifeBoard {
+        WasmLifeBoard { board: BaseLifeBoard::gen_with_density(width, height, density, Cell::from_bool) }
+    }
+
+    pub fn from_seed(width: usize, height: usize, density: f64, seed: u64) -> WasmLifeBoard {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        WasmLifeBoard { board: BaseLifeBoard::gen_with_rng(width, height, density, &mut rng, Cell::from_bool) }
+    }
+
+    pub fn width(&self) -> usize { self.board.width() }
+
+    pub fn height(&self) -> usize { self.board.height() }
+
+    pub fn population(&self) -> usize { self.board.count_alive() }
+
+    pub fn step(&mut self) { self.board.simulate(); }
+
+    pub fn step_n(&mut self, n: usize) { self.board.simulate_n_steps(n); }
+
+    pub fn is_alive(&self, x: usize, y: usize) -> bool { self.board.is_cell_alive(x, y) == Ok(true) }
+
+    // Fills `buffer` with one row-major RGBA pixel per cell, matching the layout of a canvas `ImageData`.
+    pub fn to_rgba(&self, buffer: &mut [u8]) -> Result<(), String> {
+        let (width, height) = (self.board.width(), self.board.height());
+        if buffer.len() != width * height * 4 {
+            return Err(format!("Expected a buffer of {} bytes for a {width}x{height} board but found {}.", width * height * 4, buffer.len()));
+        }
+        for (i, pixel) in buffer.chunks_exact_mut(4).enumerate() {
+            pixel.copy_from_slice(if self.is_alive(i % width, i / width) { &ALIVE_RGBA } else { &DEAD_RGBA });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+    use crate::life_implementation::{BaseLifeBoard, Cell};
+    use crate::life_interface::LifeBoard;
+    use crate::life_wasm::WasmLifeBoard;
+
+    #[test]
+    fn test_equivalence_wasm_board_steps_like_base_board() {
+        let mut wasm_board = WasmLifeBoard::from_seed(12, 9, 0.4, 3);
+        let mut board = BaseLifeBoard::gen_with_rng(12, 9, 0.4, &mut SmallRng::seed_from_u64(3), Cell::from_bool);
+        assert_eq!(board.count_alive(), wasm_board.population());
+        wasm_board.step();
+        wasm_board.step_n(4);
+        board.simulate_n_steps(5);
+        assert_eq!(board, wasm_board.board);
+    }
+
+    #[test]
+    fn test_equivalence_wasm_board_to_rgba() {
+        let wasm_board = WasmLifeBoard { board: BaseLifeBoard::from_rle("x = 2, y = 2\nbo!", Cell::from_bool).unwrap() };
+        let mut buffer = vec![0x7f; 16];
+        wasm_board.to_rgba(&mut buffer).unwrap();
+        assert_eq!(vec![0, 0, 0, 0xff, 0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0xff, 0, 0, 0, 0xff], buffer);
+        assert!(wasm_board.to_rgba(&mut [0; 12]).is_err(), "Buffers of the wrong size should be rejected");
+    }
+}
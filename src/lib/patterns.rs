@@ -0,0 +1,228 @@
+use std::fs;
+use std::path::Path;
+use crate::life_implementation::BaseLifeBoard;
+use crate::life_interface::{LifeBoard, LifeBoardError, LifeCell};
+
+const GLIDER_RLE: &str = "x = 3, y = 3\n3o$2bo$bo!";
+const BLOCK_RLE: &str = "x = 2, y = 2\n2o$2o!";
+const BLINKER_RLE: &str = "x = 1, y = 3\no$o$o!";
+const BEEHIVE_RLE: &str = "x = 3, y = 4\nbo$obo$obo$bo!";
+const TOAD_RLE: &str = "x = 2, y = 4\no$2o$2o$bo!";
+const GOSPER_GLIDER_GUN_RLE: &str = "x = 36, y = 9\n24bo$22bobo$12b2o6b2o12b2o$11bo3bo4b2o12b2o$2o8bo5bo3b2o$2o8bo3bob2o4bobo$\
+    10bo5bo7bo$11bo3bo$12b2o!";
+const MAX_MACROCELL_CELLS: usize = 1 << 24;  // Largest MacroCell square `load_dir` will expand
+
+fn from_builtin_rle<T: LifeCell>(rle: &str, init: fn(state: bool) -> T) -> BaseLifeBoard<T> {
+    BaseLifeBoard::from_rle(rle, init).expect("Built-in patterns should always be valid")
+}
+
+pub fn glider<T: LifeCell>(init: fn(state: bool) -> T) -> BaseLifeBoard<T> { from_builtin_rle(GLIDER_RLE, init) }
+
+pub fn block<T: LifeCell>(init: fn(state: bool) -> T) -> BaseLifeBoard<T> { from_builtin_rle(BLOCK_RLE, init) }
+
+pub fn blinker<T: LifeCell>(init: fn(state: bool) -> T) -> BaseLifeBoard<T> { from_builtin_rle(BLINKER_RLE, init) }
+
+pub fn beehive<T: LifeCell>(init: fn(state: bool) -> T) -> BaseLifeBoard<T> { from_builtin_rle(BEEHIVE_RLE, init) }
+
+pub fn toad<T: LifeCell>(init: fn(state: bool) -> T) -> BaseLifeBoard<T> { from_builtin_rle(TOAD_RLE, init) }
+
+pub fn gosper_glider_gun<T: LifeCell>(init: fn(state: bool) -> T) -> BaseLifeBoard<T> { from_builtin_rle(GOSPER_GLIDER_GUN_RLE, init) }
+
+// The `(x, y)` offsets in `haystack` where every alive cell of `needle` lands on an alive cell, in row order. The
+// needle's dead cells may match anything. This scans every offset against every needle cell, O(W*H*w*h); a 2D
+// convolution of the two boards (e.g. through an FFT) would scale better for large needles.
+pub fn find_pattern<T: LifeCell, U: LifeCell>(haystack: &BaseLifeBoard<T>, needle: &BaseLifeBoard<U>) -> Vec<(usize, usize)> {
+    _find_pattern(haystack, needle, false)
+}
+
+// Like `find_pattern`, but the needle's dead cells must be dead in the haystack too.
+pub fn find_pattern_exact<T: LifeCell, U: LifeCell>(haystack: &BaseLifeBoard<T>, needle: &BaseLifeBoard<U>) -> Vec<(usize, usize)> {
+    _find_pattern(haystack, needle, true)
+}
+
+fn _find_pattern<T: LifeCell, U: LifeCell>(haystack: &BaseLifeBoard<T>, needle: &BaseLifeBoard<U>, exact: bool) -> Vec<(usize, usize)> {
+    let (width, height) = (haystack.width(), haystack.height());
+    let (needle_width, needle_height) = (needle.width(), needle.height());
+    if needle_width > width || needle_height > height {
+        return Vec::new()
+    }
+    let haystack_cells = haystack.to_flat();
+    // The needle cells that have to match, as (dx, dy, alive).
+    let checks: Vec<(usize, usize, bool)> = needle.to_flat().into_iter().enumerate()
+        .filter(|&(_, alive)| exact || alive)
+        .map(|(index, alive)| (index % needle_width, index / needle_width, alive))
+        .collect();
+    (0..=height - needle_height).flat_map(|y| (0..=width - needle_width).map(move |x| (x, y)))
+        .filter(|&(x, y)| checks.iter().all(|&(dx, dy, alive)| haystack_cells[(y + dy) * width + x + dx] == alive))
+        .collect()
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum PatternFormat { Rle, Plaintext, Life106, MacroCell }
+
+impl PatternFormat {
+    pub fn from_extension(extension: &str) -> Option<PatternFormat> {
+        match extension.to_ascii_lowercase().as_str() {
+            "rle" => Some(PatternFormat::Rle),
+            "cells" => Some(PatternFormat::Plaintext),
+            "lif" | "life" => Some(PatternFormat::Life106),
+            "mc" => Some(PatternFormat::MacroCell),
+            _ => None,
+        }
+    }
+
+    // Guesses the format from the first non-comment line, falling back to plaintext.
+    pub fn sniff(text: &str) -> PatternFormat {
+        let first_line = text.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or("");
+        if first_line.starts_with("#Life 1.06") {
+            PatternFormat::Life106
+        } else if first_line.starts_with("[M2]") {
+            PatternFormat::MacroCell
+        } else if text.lines().map(str::trim_start).filter(|line| !line.starts_with('#')).any(|line| line.starts_with("x =") || line.starts_with("x=")) {
+            PatternFormat::Rle
+        } else {
+            PatternFormat::Plaintext
+        }
+    }
+
+    pub fn parse<T: LifeCell>(&self, text: &str, init: fn(state: bool) -> T) -> Result<BaseLifeBoard<T>, LifeBoardError> {
+        match self {
+            PatternFormat::Rle => BaseLifeBoard::from_rle(text, init),
+            PatternFormat::Plaintext => BaseLifeBoard::from_plaintext(text, init),
+            PatternFormat::Life106 => BaseLifeBoard::from_life106(text, init),
+            PatternFormat::MacroCell => BaseLifeBoard::from_macrocell(text, MAX_MACROCELL_CELLS, init),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct NamedPattern<T: LifeCell> {
+    /// The file name without its extension.
+    pub name: String,
+    pub format: PatternFormat,
+    pub board: BaseLifeBoard<T>,
+}
+
+#[derive(Debug)]
+pub struct LoadedPatterns<T: LifeCell> {
+    /// Every pattern that parsed, sorted by file name.
+    pub patterns: Vec<NamedPattern<T>>,
+    /// One error per file that could not be read or parsed.
+    pub warnings: Vec<LifeBoardError>,
+}
+
+// Loads every pattern file in `path`, detecting the format by extension and then by content. Only an unreadable
+// directory is an error; files that fail to load are reported in `warnings` instead.
+pub fn load_dir<T: LifeCell, P: AsRef<Path>>(path: P, init: fn(state: bool) -> T) -> Result<LoadedPatterns<T>, LifeBoardError> {
+    let path = path.as_ref();
+    let mut file_paths = fs::read_dir(path)
+        .and_then(|entries| entries.map(|entry| entry.map(|entry| entry.path())).collect::<Result<Vec<_>, _>>())
+        .map_err(|error| LifeBoardError::Io(format!("Could not read pattern directory {}: {error}", path.display())))?;
+    file_paths.retain(|file_path| file_path.is_file());
+    file_paths.sort();
+    let mut loaded = LoadedPatterns { patterns: Vec::new(), warnings: Vec::new() };
+    for file_path in file_paths {
+        match load_file(&file_path, init) {
+            Ok(pattern) => loaded.patterns.push(pattern),
+            Err(error) => loaded.warnings.push(error),
+        }
+    }
+    Ok(loaded)
+}
+
+fn load_file<T: LifeCell>(path: &Path, init: fn(state: bool) -> T) -> Result<NamedPattern<T>, LifeBoardError> {
+    let text = fs::read_to_string(path)
+        .map_err(|error| LifeBoardError::Io(format!("Could not read pattern {}: {error}", path.display())))?;
+    let format = path.extension()
+        .and_then(|extension| PatternFormat::from_extension(&extension.to_string_lossy()))
+        .unwrap_or_else(|| PatternFormat::sniff(&text));
+    let board = format.parse(&text, init).map_err(|error| LifeBoardError::Decode(
+        format!("Could not parse pattern {} as {format:?}: {error:?}", path.display())
+    ))?;
+    let name = path.file_stem().map_or_else(String::new, |stem| stem.to_string_lossy().into_owned());
+    Ok(NamedPattern { name, format, board })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::life_implementation::{BaseLifeBoard, Cell};
+    use crate::life_interface::{LifeBoard, LifeBoardError};
+    use crate::patterns::{self, PatternFormat};
+
+    // A dead 20x20 board with a glider at (2, 3) and another at (14, 11).
+    fn get_20x20_two_gliders() -> BaseLifeBoard<Cell> {
+        let mut board = BaseLifeBoard::from_bool_matrix(vec![vec![false; 20]; 20], Cell::from_bool).unwrap();
+        let glider = patterns::glider(Cell::from_bool);
+        for (left, top) in [(2, 3), (14, 11)] {
+            for (x, y) in (0..3).flat_map(|x| (0..3).map(move |y| (x, y))) {
+                if glider.is_cell_alive(x, y).unwrap() {
+                    board.fill_region(left + x, top + y, 1, 1, true).unwrap();
+                }
+            }
+        }
+        board
+    }
+
+    #[test]
+    fn test_equivalence_load_dir_skips_garbage_with_warning() {
+        let dir = std::env::temp_dir().join(format!("life_patterns_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("glider.rle"), "#N Glider\nx = 3, y = 3\n3o$2bo$bo!").unwrap();
+        fs::write(dir.join("blinker"), "!Name: Blinker\nO\nO\nO\n").unwrap();
+        fs::write(dir.join("garbage.rle"), "x = 3, y = 3\nthis is not a pattern").unwrap();
+        let loaded = patterns::load_dir(&dir, Cell::from_bool);
+        fs::remove_dir_all(&dir).unwrap();
+        let loaded = loaded.unwrap();
+        let summary: Vec<_> = loaded.patterns.iter().map(|pattern| (pattern.name.as_str(), pattern.format)).collect();
+        assert_eq!(vec![("blinker", PatternFormat::Plaintext), ("glider", PatternFormat::Rle)], summary);
+        assert_eq!(patterns::blinker(Cell::from_bool), loaded.patterns[0].board);
+        assert_eq!(patterns::glider(Cell::from_bool), loaded.patterns[1].board);
+        match &loaded.warnings[..] {
+            [LifeBoardError::Decode(error)] => assert!(error.contains("garbage.rle"), "{error}"),
+            warnings => panic!("Expected one Decode warning but found {warnings:?}"),
+        }
+    }
+
+    #[test]
+    fn test_equivalence_pattern_format_sniff() {
+        assert_eq!(PatternFormat::Life106, PatternFormat::sniff("#Life 1.06\n0 0\n"));
+        assert_eq!(PatternFormat::MacroCell, PatternFormat::sniff("[M2] (golly 4.2)\n"));
+        assert_eq!(PatternFormat::Rle, PatternFormat::sniff("#C comment\nx = 1, y = 1\no!"));
+        assert_eq!(PatternFormat::Plaintext, PatternFormat::sniff("!Name: Block\nOO\nOO\n"));
+    }
+
+    #[test]
+    fn test_exception_load_dir_missing_directory() {
+        match patterns::load_dir("does/not/exist", Cell::from_bool) {
+            Err(LifeBoardError::Io(error)) => assert!(error.contains("does/not/exist"), "{error}"),
+            result => panic!("Expected an Io error but found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_equivalence_find_pattern_two_gliders() {
+        let board = get_20x20_two_gliders();
+        let glider = patterns::glider(Cell::from_bool);
+        assert_eq!(vec![(2, 3), (14, 11)], patterns::find_pattern(&board, &glider));
+        assert_eq!(vec![(2, 3), (14, 11)], patterns::find_pattern_exact(&board, &glider));
+    }
+
+    #[test]
+    fn test_equivalence_find_pattern_ignores_needle_dead_cells() {
+        let mut board = get_20x20_two_gliders();
+        // Filling a dead cell inside the first glider's box only breaks the exact match.
+        board.fill_region(2, 4, 1, 1, true).unwrap();
+        let glider = patterns::glider(Cell::from_bool);
+        assert_eq!(vec![(2, 3), (14, 11)], patterns::find_pattern(&board, &glider));
+        assert_eq!(vec![(14, 11)], patterns::find_pattern_exact(&board, &glider));
+    }
+
+    #[test]
+    fn test_boundary_find_pattern_needle_larger_than_haystack() {
+        let glider = patterns::glider(Cell::from_bool);
+        let block = patterns::block(Cell::from_bool);
+        assert!(patterns::find_pattern(&block, &glider).is_empty());
+        assert_eq!(vec![(0, 0)], patterns::find_pattern_exact(&glider, &glider));
+    }
+}
@@ -0,0 +1,58 @@
+// Boards shared by the tests of several modules. The 7x7 board reaches its 10th generation without any cell
+// touching the edges, so every implementation has to agree on it regardless of how it treats the boundary.
+use std::fmt::Display;
+use crate::life_implementation::{BaseLifeBoard, Cell};
+use crate::life_interface::LifeBoard;
+
+pub(crate) fn get_7x7_start_board_0th_gen() -> BaseLifeBoard<Cell> {
+    BaseLifeBoard::from_bool_matrix([
+        [false, true, false, true, false, false, false],
+        [false, true, false, false, true, false, false],
+        [false, false, false, false, false, true, false],
+        [false, false, false, false, false, true, false],
+        [true, false, false, false, true, true, false],
+        [true, false, true, false, false, false, false],
+        [false, false, true, true, true, false, true],
+    ], Cell::from_bool).unwrap()
+}
+
+pub(crate) fn get_7x7_board_1st_gen() -> BaseLifeBoard<Cell> {
+    BaseLifeBoard::from_bool_matrix([
+        [false, false, true, false, false, false, false],
+        [false, false, true, false, true, false, false],
+        [false, false, false, false, true, true, false],
+        [false, false, false, false, false, true, true],
+        [false, true, false, false, true, true, false],
+        [false, false, true, false, false, false, false],
+        [false, true, true, true, false, false, false],
+    ], Cell::from_bool).unwrap()
+}
+
+pub(crate) fn get_7x7_end_board_10th_gen() -> BaseLifeBoard<Cell> {
+    BaseLifeBoard::from_bool_matrix([
+        [false, false, true, true, false, false, false],
+        [false, false, true, true, false, false, false],
+        [false, false, true, false, false, false, false],
+        [false, false, true, false, false, false, false],
+        [false, false, true, false, false, true, true],
+        [false, false, false, false, true, false, true],
+        [false, false, false, false, false, true, false],
+    ], Cell::from_bool).unwrap()
+}
+
+// Steps `board` next to `base_board`, checking after every step that both agree on the statistics, the cells, the
+// population and stability. `to_base_board` converts `board` back for the cell comparison.
+pub(crate) fn assert_matches_base_board<B, F>(base_board: BaseLifeBoard<Cell>, board: B, steps: usize, mut to_base_board: F)
+    where B: LifeBoard<Cell> + Display, F: FnMut(&B) -> BaseLifeBoard<Cell>
+{
+    let (mut base_board, mut board) = (base_board, board);
+    for step in 0..steps {
+        assert_eq!(base_board.simulate_stats(), board.simulate_stats());
+        assert_eq!(
+            base_board, to_base_board(&board),
+            "\nStep {step}\nEXPECTED:\n{base_board}\n ACTUAL:\n{board}\n"
+        );
+        assert_eq!(base_board.count_alive(), board.count_alive());
+        assert_eq!(base_board.is_stable(), board.is_stable());
+    }
+}
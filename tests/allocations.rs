@@ -0,0 +1,31 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use life::{BaseLifeBoard, Cell, LifeBoard};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_equivalence_simulate_reuses_buffers() {
+    let mut board = BaseLifeBoard::gen_seeded(200, 150, 0.4, 3, Cell::from_bool);
+    // The first step allocates the back buffer.
+    board.simulate();
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    board.simulate_n_steps(10);
+    assert_eq!(0, ALLOCATIONS.load(Ordering::SeqCst) - before);
+}
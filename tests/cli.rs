@@ -0,0 +1,52 @@
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+use life::{LifeBoard, Replay};
+
+fn run_cli(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_game_of_life_cli"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Should be able to start the CLI");
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_equivalence_cli_blinker_plaintext() {
+    let output = run_cli(&["--format", "plaintext", "--steps", "1", "--threads", "2"], ".....\n..O\n..O\n..O\n.....\n");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(".....\n.....\n.OOO.\n.....\n.....\n", String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn test_equivalence_cli_glider_rle_period() {
+    let output = run_cli(&["--steps", "4"], "x = 6, y = 6\nbo$2bo$3o!");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!("x = 6, y = 6, rule = B3/S23\n$2bo$3bo$b3o!\n", String::from_utf8(output.stdout).unwrap());
+}
+
+#[test]
+fn test_exception_cli_exit_codes() {
+    let output = run_cli(&["--steps", "1"], "not rle");
+    assert_eq!(Some(3), output.status.code());
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8(output.stderr).unwrap().contains("Unable to parse board"));
+    let output = run_cli(&["--format", "mc"], "");
+    assert_eq!(Some(2), output.status.code());
+    assert!(String::from_utf8(output.stderr).unwrap().contains("Usage"));
+}
+
+#[test]
+fn test_equivalence_cli_record_replay() {
+    let path = std::env::temp_dir().join(format!("life_cli_replay_{}.bin", std::process::id()));
+    let output = run_cli(&["--steps", "4", "--record", path.to_str().unwrap()], "x = 6, y = 6\nbo$2bo$3o!");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let replay = Replay::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(5, replay.frames().len());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), replay.play_at(4).unwrap().to_rle());
+}
@@ -0,0 +1,21 @@
+use life::{BaseLifeBoard, Cell, LifeBoard, LifeBoardError};
+
+#[test]
+fn test_equivalence_board_from_bool_matrix() {
+    let columns: Vec<Vec<bool>> = vec![vec![false, true, false], vec![false, true, false], vec![false, true, false]];
+    let mut board = BaseLifeBoard::from_bool_matrix(columns, Cell::from_bool).unwrap();
+    assert_eq!((3, 3, 3), (board.width(), board.height(), board.count_alive()));
+    board.simulate();
+    let expected_board = BaseLifeBoard::from_cell_matrix(
+        [[false, false, false], [true, true, true], [false, false, false]].map(|col| col.map(Cell::from_bool))
+    ).unwrap();
+    assert_eq!(expected_board, board);
+}
+
+#[test]
+fn test_exception_board_from_ragged_bool_matrix() {
+    match BaseLifeBoard::from_bool_matrix(vec![vec![true, false], vec![true]], Cell::from_bool) {
+        Err(LifeBoardError::InvalidBoard(error)) => assert!(error.contains("consistent size"), "{error}"),
+        result => panic!("Expected an invalid board error but found {result:?}"),
+    }
+}